@@ -20,6 +20,7 @@ use starcoin_vm_types::move_resource::MoveResource;
 use starcoin_vm_types::token::stc::STC_TOKEN_CODE_STR;
 use starcoin_vm_types::transaction::{DryRunTransaction, RawUserTransaction, TransactionPayload};
 use std::convert::TryInto;
+use std::fs::File;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
@@ -42,6 +43,7 @@ impl CliState {
     pub const DEFAULT_GAS_PRICE: u64 = 1;
     pub const DEFAULT_EXPIRATION_TIME_SECS: u64 = 3600;
     pub const DEFAULT_GAS_TOKEN: &'static str = STC_TOKEN_CODE_STR;
+    pub const GAS_BUDGET_ESTIMATE_MARGIN_PERCENT: u64 = 20;
 
     pub fn new(
         net: ChainNetworkID,
@@ -171,11 +173,22 @@ impl CliState {
         txn_opts: TransactionOptions,
         payload: TransactionPayload,
     ) -> Result<ExecuteResultView> {
+        let max_gas_amount = if txn_opts.gas_budget_estimate {
+            let gas_used = self.estimate_gas_used(&txn_opts, payload.clone())?;
+            let gas_budget = gas_used + gas_used * Self::GAS_BUDGET_ESTIMATE_MARGIN_PERCENT / 100;
+            eprintln!(
+                "estimated gas used: {}, gas budget with margin: {}",
+                gas_used, gas_budget
+            );
+            txn_opts.max_gas_amount.or(Some(gas_budget))
+        } else {
+            txn_opts.max_gas_amount
+        };
         let (raw_txn, future_transaction) = self.build_transaction(
             txn_opts.sender,
             txn_opts.sequence_number,
             txn_opts.gas_unit_price,
-            txn_opts.max_gas_amount,
+            max_gas_amount,
             txn_opts.expiration_time_secs,
             payload,
         )?;
@@ -183,9 +196,79 @@ impl CliState {
             //TODO figure out more graceful method to handle future transaction.
             bail!("there is transaction from sender({}) in the txpool, please wait it to been executed or use sequence_number({}) to replace it.",raw_txn.sender(), raw_txn.sequence_number()-1);
         }
+        if let Some(unsigned_output) = txn_opts.unsigned_output.as_ref() {
+            let dry_output = if txn_opts.skip_dry_run {
+                None
+            } else {
+                let sender = self.client.account_get(raw_txn.sender())?.ok_or_else(|| {
+                    format_err!(
+                        "Can not dry run the txn, sender {} is not a local account, import it by public key or use --skip-dry-run.",
+                        raw_txn.sender()
+                    )
+                })?;
+                let dry_output = self.client.dry_run_raw(DryRunTransaction {
+                    public_key: sender.public_key,
+                    raw_txn: raw_txn.clone(),
+                })?;
+                if !matches!(
+                    dry_output.txn_output.status,
+                    TransactionStatusView::Executed
+                ) {
+                    bail!(
+                        "txn dry run failed, status: {:?}, explained: {:?}, the unsigned txn is not written.",
+                        dry_output.txn_output.status,
+                        dry_output.explained_status
+                    );
+                }
+                Some(dry_output)
+            };
+            let mut file = File::create(unsigned_output)?;
+            bcs_ext::serialize_into(&mut file, &raw_txn)?;
+            eprintln!(
+                "unsigned txn is written to {:?}, sign it and submit the signed txn by `account submit-txn`.",
+                unsigned_output
+            );
+            return Ok(ExecuteResultView::new(
+                self.raw_txn_view(&raw_txn)?,
+                raw_txn.to_hex(),
+                dry_output,
+            ));
+        }
         self.execute_transaction(raw_txn, txn_opts.dry_run, txn_opts.blocking)
     }
 
+    /// Dry run the payload with the default max gas amount, return the gas used.
+    fn estimate_gas_used(
+        &self,
+        txn_opts: &TransactionOptions,
+        payload: TransactionPayload,
+    ) -> Result<u64> {
+        let (raw_txn, _) = self.build_transaction(
+            txn_opts.sender,
+            txn_opts.sequence_number,
+            txn_opts.gas_unit_price,
+            None,
+            txn_opts.expiration_time_secs,
+            payload,
+        )?;
+        let sender = self.get_account(raw_txn.sender())?;
+        let dry_output = self.client.dry_run_raw(DryRunTransaction {
+            public_key: sender.public_key,
+            raw_txn,
+        })?;
+        if !matches!(
+            dry_output.txn_output.status,
+            TransactionStatusView::Executed
+        ) {
+            bail!(
+                "estimate gas failed, txn dry run status: {:?}, explained: {:?}",
+                dry_output.txn_output.status,
+                dry_output.explained_status
+            );
+        }
+        Ok(dry_output.txn_output.gas_used.0)
+    }
+
     fn build_transaction(
         &self,
         sender: Option<AccountAddress>,
@@ -196,22 +279,23 @@ impl CliState {
         payload: TransactionPayload,
     ) -> Result<(RawUserTransaction, bool)> {
         let chain_id = self.net().chain_id();
-        let sender = self.get_account_or_default(sender)?;
+        // the sender is not required to be a local account, for signing the txn offline.
+        let sender = match sender {
+            Some(sender) => sender,
+            None => self.default_account()?.address,
+        };
         let (sequence_number, future_transaction) = match sequence_number {
             Some(sequence_number) => (sequence_number, false),
-            None => match self.client.next_sequence_number_in_txpool(sender.address)? {
+            None => match self.client.next_sequence_number_in_txpool(sender)? {
                 Some(sequence_number) => {
                     eprintln!("get sequence_number {} from txpool", sequence_number);
                     (sequence_number, true)
                 }
                 None => self
-                    .get_account_resource(*sender.address())?
+                    .get_account_resource(sender)?
                     .map(|account| (account.sequence_number(), false))
                     .ok_or_else(|| {
-                        format_err!(
-                            "Can not find account on chain by address:{}",
-                            sender.address()
-                        )
+                        format_err!("Can not find account on chain by address:{}", sender)
                     })?,
             },
         };
@@ -221,7 +305,7 @@ impl CliState {
             + node_info.now_seconds;
         Ok((
             RawUserTransaction::new(
-                sender.address,
+                sender,
                 sequence_number,
                 payload,
                 max_gas_amount.unwrap_or(Self::DEFAULT_MAX_GAS_AMOUNT),
@@ -245,18 +329,17 @@ impl CliState {
             public_key: sender.public_key,
             raw_txn: raw_txn.clone(),
         })?;
-        let mut raw_txn_view: RawUserTransactionView = raw_txn.clone().try_into()?;
-        raw_txn_view.decoded_payload =
-            Some(self.decode_txn_payload(raw_txn.payload())?.try_into()?);
-
-        let mut execute_result = ExecuteResultView::new(raw_txn_view, raw_txn.to_hex(), dry_output);
+        let executed = matches!(
+            dry_output.txn_output.status,
+            TransactionStatusView::Executed
+        );
+        let mut execute_result = ExecuteResultView::new(
+            self.raw_txn_view(&raw_txn)?,
+            raw_txn.to_hex(),
+            Some(dry_output),
+        );
 
-        if only_dry_run
-            || !matches!(
-                execute_result.dry_run_output.txn_output.status,
-                TransactionStatusView::Executed
-            )
-        {
+        if only_dry_run || !executed {
             eprintln!("txn dry run failed");
             return Ok(execute_result);
         }
@@ -274,6 +357,13 @@ impl CliState {
         Ok(execute_result)
     }
 
+    fn raw_txn_view(&self, raw_txn: &RawUserTransaction) -> Result<RawUserTransactionView> {
+        let mut raw_txn_view: RawUserTransactionView = raw_txn.clone().try_into()?;
+        raw_txn_view.decoded_payload =
+            Some(self.decode_txn_payload(raw_txn.payload())?.try_into()?);
+        Ok(raw_txn_view)
+    }
+
     pub fn decode_txn_payload(
         &self,
         payload: &TransactionPayload,
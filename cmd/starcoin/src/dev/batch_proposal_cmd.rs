@@ -0,0 +1,253 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_state::CliState;
+use crate::dev::dev_helper;
+use crate::dev::sign_txn_helper::get_dao_config;
+use crate::view::{ExecuteResultView, TransactionOptions};
+use crate::StarcoinOpt;
+use anyhow::{bail, ensure, Result};
+use scmd::{CommandAction, ExecContext};
+use starcoin_config::BuiltinNetworkID;
+use starcoin_rpc_api::types::TransactionStatusView;
+use starcoin_transaction_builder::{
+    build_consensus_config_upgrade_proposal, build_gas_schedule_upgrade_proposal,
+    build_vm_config_upgrade_proposal,
+};
+use starcoin_vm_types::language_storage::TypeTag;
+use starcoin_vm_types::on_chain_config::{consensus_config_type_tag, vm_config_type_tag};
+use starcoin_vm_types::transaction::TransactionPayload;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use structopt::StructOpt;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ProposalKind {
+    VMConfig,
+    GasSchedule,
+    ConsensusConfig,
+}
+
+impl FromStr for ProposalKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "vm-config" => ProposalKind::VMConfig,
+            "gas-schedule" => ProposalKind::GasSchedule,
+            "consensus-config" => ProposalKind::ConsensusConfig,
+            _ => bail!(
+                "Unknown proposal kind: {}, should be one of vm-config, gas-schedule, consensus-config.",
+                s
+            ),
+        })
+    }
+}
+
+impl ProposalKind {
+    /// The on chain config type updated by the proposal.
+    pub fn config_type_tag(self) -> TypeTag {
+        match self {
+            // the gas schedule proposal updates the whole VMConfig.
+            ProposalKind::VMConfig | ProposalKind::GasSchedule => vm_config_type_tag(),
+            ProposalKind::ConsensusConfig => consensus_config_type_tag(),
+        }
+    }
+}
+
+impl fmt::Display for ProposalKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = match self {
+            ProposalKind::VMConfig => "vm-config",
+            ProposalKind::GasSchedule => "gas-schedule",
+            ProposalKind::ConsensusConfig => "consensus-config",
+        };
+        write!(f, "{}", kind)
+    }
+}
+
+/// Submit several config upgrade proposals one by one with the same account.
+/// All proposals are dry run first, and nothing is submitted if any dry run fails.
+/// The batch is not atomic: it stops at the first failed proposal, and the proposals
+/// submitted before it stay on chain.
+/// An account can only have one proposal of each action type, so vm-config and gas-schedule,
+/// which both update the VMConfig, can not be in the same batch.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "batch-proposal", alias = "batch_proposal")]
+pub struct BatchProposalOpt {
+    #[structopt(flatten)]
+    transaction_opts: TransactionOptions,
+
+    #[structopt(name = "kind", long = "kind", use_delimiter = true, required = true)]
+    /// The proposal kinds to submit in order, multi kind should use ',' as delimiter.
+    /// Support vm-config, gas-schedule and consensus-config.
+    kinds: Vec<ProposalKind>,
+
+    #[structopt(short = "n", name = "net", long = "net")]
+    /// The network id for copy config, conflicts with net-config-path
+    net: Option<BuiltinNetworkID>,
+
+    #[structopt(
+        name = "net-config-path",
+        long = "net-config-path",
+        parse(from_os_str),
+        conflicts_with("net")
+    )]
+    /// The genesis config json file for copy config, used when the network is not a builtin network
+    net_config_path: Option<PathBuf>,
+}
+
+pub struct BatchProposalCommand;
+
+impl CommandAction for BatchProposalCommand {
+    type State = CliState;
+    type GlobalOpt = StarcoinOpt;
+    type Opt = BatchProposalOpt;
+    type ReturnItem = Vec<ExecuteResultView>;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let opt = ctx.opt();
+        ensure!(
+            opt.transaction_opts.unsigned_output.is_none(),
+            "batch-proposal does not support --unsigned-output."
+        );
+        check_distinct_config_types(&opt.kinds)?;
+
+        let genesis_config =
+            dev_helper::load_genesis_config(opt.net, opt.net_config_path.as_ref())?;
+        let min_action_delay = get_dao_config(ctx.state())?.min_action_delay;
+        let payloads = opt
+            .kinds
+            .iter()
+            .map(|kind| {
+                let script_function = match kind {
+                    ProposalKind::VMConfig => build_vm_config_upgrade_proposal(
+                        genesis_config.vm_config.clone(),
+                        min_action_delay,
+                    ),
+                    ProposalKind::GasSchedule => build_gas_schedule_upgrade_proposal(
+                        genesis_config.vm_config.gas_schedule.clone(),
+                        min_action_delay,
+                    ),
+                    ProposalKind::ConsensusConfig => build_consensus_config_upgrade_proposal(
+                        genesis_config.consensus_config.clone(),
+                        min_action_delay,
+                    ),
+                };
+                (*kind, TransactionPayload::ScriptFunction(script_function))
+            })
+            .collect::<Vec<_>>();
+
+        // The proposals update different configs, so each one can be checked against the
+        // current chain state alone.
+        let mut dry_run_results = vec![];
+        for (kind, payload) in &payloads {
+            let mut txn_opts = opt.transaction_opts.clone();
+            txn_opts.dry_run = true;
+            txn_opts.blocking = false;
+            let result = ctx
+                .state()
+                .build_and_execute_transaction(txn_opts, payload.clone())?;
+            let dry_run_output = result
+                .dry_run_output
+                .as_ref()
+                .expect("dry run output should exist without unsigned-output.");
+            if !matches!(
+                dry_run_output.txn_output.status,
+                TransactionStatusView::Executed
+            ) {
+                bail!(
+                    "proposal {} dry run failed, status: {:?}, explained: {:?}, nothing is submitted.",
+                    kind,
+                    dry_run_output.txn_output.status,
+                    dry_run_output.explained_status
+                );
+            }
+            dry_run_results.push(result);
+        }
+        if opt.transaction_opts.dry_run {
+            return Ok(dry_run_results);
+        }
+
+        let mut results = vec![];
+        let mut succeeded = vec![];
+        for (idx, (kind, payload)) in payloads.into_iter().enumerate() {
+            let mut txn_opts = opt.transaction_opts.clone();
+            // wait every proposal executed, so the next one get the sequence number from chain.
+            txn_opts.blocking = true;
+            txn_opts.sequence_number = opt
+                .transaction_opts
+                .sequence_number
+                .map(|sequence_number| sequence_number + idx as u64);
+            let result = ctx
+                .state()
+                .build_and_execute_transaction(txn_opts, payload)?;
+            let status = match result.execute_output.as_ref() {
+                Some(execute_output) => execute_output
+                    .txn_info
+                    .as_ref()
+                    .map(|txn_info| txn_info.status.clone()),
+                None => result
+                    .dry_run_output
+                    .as_ref()
+                    .map(|dry_run_output| dry_run_output.txn_output.status.clone()),
+            };
+            if !matches!(status, Some(TransactionStatusView::Executed)) {
+                bail!(
+                    "proposal {} failed, status: {:?}, the batch is not atomic, succeeded proposals: {:?}",
+                    kind,
+                    status,
+                    succeeded
+                );
+            }
+            succeeded.push(match result.execute_output.as_ref() {
+                Some(execute_output) => format!("{}: {}", kind, execute_output.txn_hash),
+                None => kind.to_string(),
+            });
+            results.push(result);
+        }
+        Ok(results)
+    }
+}
+
+/// Reject the kinds updating the same config, the second proposal would fail on chain
+/// as the first one already exists.
+fn check_distinct_config_types(kinds: &[ProposalKind]) -> Result<()> {
+    for (idx, kind) in kinds.iter().enumerate() {
+        if let Some(conflict) = kinds[..idx]
+            .iter()
+            .find(|other| other.config_type_tag() == kind.config_type_tag())
+        {
+            bail!(
+                "proposal {} and {} both update {}, submit them in different batches.",
+                conflict,
+                kind,
+                kind.config_type_tag()
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_check_distinct_config_types() {
+    assert!(
+        check_distinct_config_types(&[ProposalKind::VMConfig, ProposalKind::ConsensusConfig])
+            .is_ok()
+    );
+    assert!(check_distinct_config_types(&[
+        ProposalKind::VMConfig,
+        ProposalKind::ConsensusConfig,
+        ProposalKind::GasSchedule
+    ])
+    .is_err());
+    assert!(check_distinct_config_types(&[
+        ProposalKind::ConsensusConfig,
+        ProposalKind::ConsensusConfig
+    ])
+    .is_err());
+}
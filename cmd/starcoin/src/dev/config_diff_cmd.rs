@@ -0,0 +1,68 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_state::CliState;
+use crate::dev::dev_helper;
+use crate::dev::sign_txn_helper::get_vm_config;
+use crate::view::ConfigDiffView;
+use crate::StarcoinOpt;
+use anyhow::{ensure, Result};
+use scmd::{CommandAction, ExecContext};
+use starcoin_config::BuiltinNetworkID;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Show the difference between the on chain VM config and the VM config of the network's genesis config.
+/// Return `equal: true` if there is no difference, or fail with `--fail-if-equal`.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "config-diff", alias = "config_diff")]
+pub struct ConfigDiffOpt {
+    #[structopt(short = "n", name = "net", long = "net")]
+    /// The network id for compare config, conflicts with net-config-path
+    net: Option<BuiltinNetworkID>,
+
+    #[structopt(
+        name = "net-config-path",
+        long = "net-config-path",
+        parse(from_os_str),
+        conflicts_with("net")
+    )]
+    /// The genesis config json file for compare config, used when the network is not a builtin network
+    net_config_path: Option<PathBuf>,
+
+    #[structopt(name = "fail-if-equal", long = "fail-if-equal")]
+    /// Exit with a nonzero status if there is no difference, for use in scripts.
+    fail_if_equal: bool,
+}
+
+pub struct ConfigDiffCommand;
+
+impl CommandAction for ConfigDiffCommand {
+    type State = CliState;
+    type GlobalOpt = StarcoinOpt;
+    type Opt = ConfigDiffOpt;
+    type ReturnItem = ConfigDiffView;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let opt = ctx.opt();
+
+        let genesis_config =
+            dev_helper::load_genesis_config(opt.net, opt.net_config_path.as_ref())?;
+        let current_vm_config = get_vm_config(ctx.state())?;
+        let diff = dev_helper::gas_schedule_diff(
+            &current_vm_config.gas_schedule,
+            &genesis_config.vm_config.gas_schedule,
+        );
+        ensure!(
+            !(opt.fail_if_equal && diff.is_empty()),
+            "The VM config is same as the on chain VM config."
+        );
+        Ok(ConfigDiffView {
+            equal: diff.is_empty(),
+            diff,
+        })
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_state::CliState;
+use crate::dev::sign_txn_helper::{
+    get_proposal_state, proposal_state_name, ACTIVE, AGREED, DEFEATED, EXECUTABLE, EXTRACTED,
+    PENDING, QUEUED,
+};
+use crate::view::{ExecuteResultView, TransactionOptions};
+use crate::StarcoinOpt;
+use anyhow::{bail, format_err, Result};
+use scmd::{CommandAction, ExecContext};
+use starcoin_rpc_api::types::TypeTagView;
+use starcoin_transaction_builder::{
+    build_on_chain_config_proposal_destroy, on_chain_config_update_type_tag,
+};
+use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::language_storage::TypeTag;
+use starcoin_vm_types::token::token_code::TokenCode;
+use starcoin_vm_types::transaction::TransactionPayload;
+use std::convert::TryInto;
+use structopt::StructOpt;
+
+/// Destroy a defeated on chain config proposal, to clean up the proposal resource under the
+/// proposer's account. `Dao::destroy_terminated_proposal` takes no signer, so any account can send
+/// it. A pending or active proposal can not be cancelled, and an extracted proposal is kept as
+/// the record of the executed config change.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "dao-destroy-proposal", alias = "dao_destroy_proposal")]
+pub struct DaoDestroyProposalOpt {
+    #[structopt(flatten)]
+    transaction_opts: TransactionOptions,
+
+    #[structopt(short = "a", name = "proposer-address", long = "proposer_address")]
+    /// the account address for proposer, default is the sender.
+    proposer_address: Option<AccountAddress>,
+
+    #[structopt(
+        short = "i",
+        name = "proposal-id",
+        long = "proposal-id",
+        help = "proposal id."
+    )]
+    proposal_id: u64,
+
+    #[structopt(name = "config-type", long = "config-type")]
+    /// The on chain config type of the proposal, eg: 0x1::VMConfig::VMConfig
+    config_type: TypeTagView,
+
+    #[structopt(
+        name = "dao-token",
+        long = "dao-token",
+        default_value = "0x1::STC::STC"
+    )]
+    /// The token for dao governance, default is 0x1::STC::STC
+    dao_token: TokenCode,
+}
+
+pub struct DaoDestroyProposalCommand;
+
+impl CommandAction for DaoDestroyProposalCommand {
+    type State = CliState;
+    type GlobalOpt = StarcoinOpt;
+    type Opt = DaoDestroyProposalOpt;
+    type ReturnItem = ExecuteResultView;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let opt = ctx.opt();
+        let proposer_address = if let Some(proposer_address) = opt.proposer_address {
+            proposer_address
+        } else if let Some(sender) = opt.transaction_opts.sender {
+            sender
+        } else {
+            ctx.state().default_account()?.address
+        };
+
+        let token_type: TypeTag = opt.dao_token.clone().try_into()?;
+        let action_type = on_chain_config_update_type_tag(opt.config_type.0.clone());
+        let state = get_proposal_state(
+            ctx.state(),
+            token_type,
+            action_type,
+            proposer_address,
+            opt.proposal_id,
+        )
+        .map_err(|e| {
+            format_err!(
+                "Can not find the proposal {} of {}: {:?}",
+                opt.proposal_id,
+                proposer_address,
+                e
+            )
+        })?;
+        check_destroyable(state, opt.proposal_id)?;
+
+        let proposal_destroy = build_on_chain_config_proposal_destroy(
+            proposer_address,
+            opt.proposal_id,
+            opt.dao_token.clone(),
+            opt.config_type.0.clone(),
+        );
+        ctx.state().build_and_execute_transaction(
+            opt.transaction_opts.clone(),
+            TransactionPayload::ScriptFunction(proposal_destroy),
+        )
+    }
+}
+
+fn check_destroyable(state: u64, proposal_id: u64) -> Result<()> {
+    match state {
+        DEFEATED => Ok(()),
+        PENDING | ACTIVE => bail!(
+            "Proposal {} is {}, the Dao does not allow cancelling it before voting ends.",
+            proposal_id,
+            proposal_state_name(state)
+        ),
+        AGREED | QUEUED | EXECUTABLE => bail!(
+            "Proposal {} is {}, it has passed the vote and can not be destroyed, it can only be executed.",
+            proposal_id,
+            proposal_state_name(state)
+        ),
+        EXTRACTED => bail!(
+            "Proposal {} is {}, it has been executed and is kept as the record of the config change.",
+            proposal_id,
+            proposal_state_name(state)
+        ),
+        _ => bail!(
+            "Proposal {} can not be destroyed, current state: {}.",
+            proposal_id,
+            state
+        ),
+    }
+}
+
+#[test]
+fn test_check_destroyable() {
+    assert!(check_destroyable(DEFEATED, 1).is_ok());
+    assert!(check_destroyable(ACTIVE, 1).is_err());
+    let err = check_destroyable(EXECUTABLE, 1).unwrap_err();
+    assert!(err.to_string().contains("executable"));
+    assert!(check_destroyable(QUEUED, 1).is_err());
+    let err = check_destroyable(EXTRACTED, 1).unwrap_err();
+    assert!(err.to_string().contains("executed"));
+}
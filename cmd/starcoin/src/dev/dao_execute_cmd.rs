@@ -0,0 +1,120 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_state::CliState;
+use crate::dev::dao_list_proposals_cmd::json_u64;
+use crate::dev::sign_txn_helper::{
+    get_proposal_json, get_proposal_state, proposal_state_name, AGREED, EXECUTABLE, QUEUED,
+};
+use crate::view::{ExecuteResultView, TransactionOptions};
+use crate::StarcoinOpt;
+use anyhow::{bail, Result};
+use scmd::{CommandAction, ExecContext};
+use starcoin_rpc_api::types::TypeTagView;
+use starcoin_transaction_builder::{
+    build_on_chain_config_proposal_execute, on_chain_config_update_type_tag,
+};
+use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::language_storage::TypeTag;
+use starcoin_vm_types::token::token_code::TokenCode;
+use starcoin_vm_types::transaction::TransactionPayload;
+use std::convert::TryInto;
+use structopt::StructOpt;
+
+/// Execute a queued on chain config proposal
+#[derive(Debug, StructOpt)]
+#[structopt(name = "dao-execute", alias = "dao_execute")]
+pub struct DaoExecuteOpt {
+    #[structopt(flatten)]
+    transaction_opts: TransactionOptions,
+
+    #[structopt(short = "a", name = "proposer-address", long = "proposer_address")]
+    /// the account address for proposer.
+    proposer_address: Option<AccountAddress>,
+
+    #[structopt(
+        short = "i",
+        name = "proposal-id",
+        long = "proposal-id",
+        help = "proposal id."
+    )]
+    proposal_id: u64,
+
+    #[structopt(name = "config-type", long = "config-type")]
+    /// The on chain config type of the proposal, eg: 0x1::VMConfig::VMConfig
+    config_type: TypeTagView,
+
+    #[structopt(
+        name = "dao-token",
+        long = "dao-token",
+        default_value = "0x1::STC::STC"
+    )]
+    /// The token for dao governance, default is 0x1::STC::STC
+    dao_token: TokenCode,
+}
+
+pub struct DaoExecuteCommand;
+
+impl CommandAction for DaoExecuteCommand {
+    type State = CliState;
+    type GlobalOpt = StarcoinOpt;
+    type Opt = DaoExecuteOpt;
+    type ReturnItem = ExecuteResultView;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let opt = ctx.opt();
+        let proposer_address = if let Some(address) = opt.proposer_address {
+            address
+        } else if let Some(sender) = opt.transaction_opts.sender {
+            sender
+        } else {
+            ctx.state().default_account()?.address
+        };
+
+        let token_type: TypeTag = opt.dao_token.clone().try_into()?;
+        let action_type = on_chain_config_update_type_tag(opt.config_type.0.clone());
+        let state = get_proposal_state(
+            ctx.state(),
+            token_type.clone(),
+            action_type.clone(),
+            proposer_address,
+            opt.proposal_id,
+        )?;
+        match state {
+            EXECUTABLE => {}
+            QUEUED => {
+                let proposal_json =
+                    get_proposal_json(ctx.state(), token_type, action_type, proposer_address)?;
+                bail!(
+                    "Proposal {} is queued, it can be executed at {}, after its action_delay({} ms) elapsed since queued.",
+                    opt.proposal_id,
+                    json_u64(&proposal_json, "eta")?,
+                    json_u64(&proposal_json, "action_delay")?
+                )
+            }
+            AGREED => bail!(
+                "Proposal {} is agreed but not queued, please queue it first.",
+                opt.proposal_id
+            ),
+            _ => bail!(
+                "Proposal {} is not executable, current state: {}.",
+                opt.proposal_id,
+                proposal_state_name(state)
+            ),
+        }
+
+        let proposal_execute = build_on_chain_config_proposal_execute(
+            proposer_address,
+            opt.proposal_id,
+            opt.dao_token.clone(),
+            opt.config_type.0.clone(),
+        );
+        ctx.state().build_and_execute_transaction(
+            opt.transaction_opts.clone(),
+            TransactionPayload::ScriptFunction(proposal_execute),
+        )
+    }
+}
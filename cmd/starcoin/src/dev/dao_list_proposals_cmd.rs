@@ -0,0 +1,117 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_state::CliState;
+use crate::dev::sign_txn_helper::{
+    get_proposal_state, proposal_state_name, ACTIVE, PENDING, QUEUED,
+};
+use crate::view::ProposalView;
+use crate::StarcoinOpt;
+use anyhow::{format_err, Result};
+use scmd::{CommandAction, ExecContext};
+use serde_json::Value;
+use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::account_config::core_code_address;
+use starcoin_vm_types::language_storage::StructTag;
+use structopt::StructOpt;
+
+/// List the dao proposals of the proposers with their state.
+/// The proposal is stored under the proposer's account, so the proposers should be given.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "dao-list-proposals", alias = "dao_list_proposals")]
+pub struct DaoListProposalsOpt {
+    #[structopt(short = "a", name = "proposer-address", long = "proposer_address")]
+    /// the account addresses for proposer, if absent, use default account.
+    proposer_addresses: Vec<AccountAddress>,
+}
+
+pub struct DaoListProposalsCommand;
+
+impl CommandAction for DaoListProposalsCommand {
+    type State = CliState;
+    type GlobalOpt = StarcoinOpt;
+    type Opt = DaoListProposalsOpt;
+    type ReturnItem = Vec<ProposalView>;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let opt = ctx.opt();
+        let proposer_addresses = if opt.proposer_addresses.is_empty() {
+            vec![ctx.state().default_account()?.address]
+        } else {
+            opt.proposer_addresses.clone()
+        };
+        let now_millis = ctx.state().client().node_info()?.now_seconds * 1000;
+
+        let mut proposals = vec![];
+        for proposer_address in proposer_addresses {
+            let all_resources =
+                ctx.state()
+                    .client()
+                    .state_list_resource(proposer_address, true, None)?;
+            for (resource_type, resource) in all_resources.resources {
+                if !is_proposal_type(&resource_type.0) {
+                    continue;
+                }
+                let proposal_json = resource
+                    .json
+                    .ok_or_else(|| {
+                        format_err!("Proposal resource {} is not decoded.", resource_type.0)
+                    })?
+                    .0;
+                let id = json_u64(&proposal_json, "id")?;
+                let token_type = resource_type.0.type_params[0].clone();
+                let action_type = resource_type.0.type_params[1].clone();
+                let state = get_proposal_state(
+                    ctx.state(),
+                    token_type.clone(),
+                    action_type.clone(),
+                    proposer_address,
+                    id,
+                )?;
+                let phase_end_time = match state {
+                    PENDING => Some(json_u64(&proposal_json, "start_time")?),
+                    ACTIVE => Some(json_u64(&proposal_json, "end_time")?),
+                    QUEUED => Some(json_u64(&proposal_json, "eta")?),
+                    _ => None,
+                };
+                proposals.push(ProposalView {
+                    proposer: proposer_address,
+                    id,
+                    token: token_type.into(),
+                    action: action_type.into(),
+                    state: proposal_state_name(state).to_string(),
+                    for_votes: json_u128(&proposal_json, "for_votes")?,
+                    against_votes: json_u128(&proposal_json, "against_votes")?,
+                    quorum_votes: json_u128(&proposal_json, "quorum_votes")?,
+                    remaining_time: phase_end_time
+                        .map(|end_time| end_time.saturating_sub(now_millis)),
+                });
+            }
+        }
+        Ok(proposals)
+    }
+}
+
+pub(crate) fn is_proposal_type(struct_tag: &StructTag) -> bool {
+    struct_tag.address == core_code_address()
+        && struct_tag.module.as_str() == "Dao"
+        && struct_tag.name.as_str() == "Proposal"
+        && struct_tag.type_params.len() == 2
+}
+
+pub(crate) fn json_u64(json_value: &Value, field: &str) -> Result<u64> {
+    json_value
+        .get(field)
+        .and_then(|value| value.as_u64())
+        .ok_or_else(|| format_err!("invalid json, parse {} failed, {}", field, json_value))
+}
+
+pub(crate) fn json_u128(json_value: &Value, field: &str) -> Result<u128> {
+    json_value
+        .get(field)
+        .and_then(|value| value.to_string().parse::<u128>().ok())
+        .ok_or_else(|| format_err!("invalid json, parse {} failed, {}", field, json_value))
+}
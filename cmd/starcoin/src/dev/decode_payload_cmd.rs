@@ -0,0 +1,44 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_state::CliState;
+use crate::view::FilePathOrHex;
+use crate::StarcoinOpt;
+use anyhow::{format_err, Result};
+use scmd::{CommandAction, ExecContext};
+use starcoin_rpc_api::types::TransactionPayloadView;
+use starcoin_vm_types::transaction::TransactionPayload;
+use structopt::StructOpt;
+
+/// Decode a bcs encoded transaction payload, print it in the same format as `--output-json` of the proposal commands.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "decode-payload", alias = "decode_payload")]
+pub struct DecodePayloadOpt {
+    #[structopt(name = "payload")]
+    /// The bcs encoded transaction payload, hex string with 0x prefix or file path.
+    payload: FilePathOrHex,
+}
+
+pub struct DecodePayloadCommand;
+
+impl CommandAction for DecodePayloadCommand {
+    type State = CliState;
+    type GlobalOpt = StarcoinOpt;
+    type Opt = DecodePayloadOpt;
+    type ReturnItem = TransactionPayloadView;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let opt = ctx.opt();
+        let bytes = opt.payload.as_bytes()?;
+        let payload: TransactionPayload = bcs_ext::from_bytes(&bytes).map_err(|e| {
+            format_err!(
+                "Decode TransactionPayload failed {:?}, please ensure the input is a bcs encoded TransactionPayload.",
+                e
+            )
+        })?;
+        Ok(ctx.state().decode_txn_payload(&payload)?.into())
+    }
+}
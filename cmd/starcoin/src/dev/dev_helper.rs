@@ -1,12 +1,24 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::{ensure, format_err, Result};
+use crate::cli_state::CliState;
+use crate::view::{ExecuteResultView, TransactionOptions};
+use anyhow::{bail, ensure, format_err, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use starcoin_config::{BuiltinNetworkID, GenesisConfig};
 use starcoin_move_compiler::move_command_line_common::files::MOVE_COMPILED_EXTENSION;
-use starcoin_vm_types::transaction::{Module, Package};
+use starcoin_rpc_api::types::{TransactionPayloadView, TransactionStatusView, WriteOpValueView};
+use starcoin_transaction_builder::on_chain_config_update_type_tag;
+use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::account_config::genesis_address;
+use starcoin_vm_types::gas_schedule::{CostTable, GasCost};
+use starcoin_vm_types::language_storage::{StructTag, TypeTag};
+use starcoin_vm_types::on_chain_config::{ConsensusConfig, VMConfig, VMConfigInner};
+use starcoin_vm_types::transaction::{Module, Package, TransactionPayload};
 use std::fs::File;
 use std::io::Read;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 pub fn load_package_from_file(mv_or_package_file: &Path) -> Result<Package> {
     ensure!(
@@ -30,3 +42,182 @@ pub fn load_package_from_file(mv_or_package_file: &Path) -> Result<Package> {
     };
     Ok(package)
 }
+
+/// Print the decoded payload as json to stdout, for reviewing it before submit.
+pub fn print_payload_json(cli_state: &CliState, payload: &TransactionPayload) -> Result<()> {
+    let payload_view: TransactionPayloadView = cli_state.decode_txn_payload(payload)?.into();
+    println!("{}", serde_json::to_string_pretty(&payload_view)?);
+    Ok(())
+}
+
+/// Load the genesis config for copy config from the builtin network or a genesis config json file.
+pub fn load_genesis_config(
+    net: Option<BuiltinNetworkID>,
+    net_config_path: Option<&PathBuf>,
+) -> Result<GenesisConfig> {
+    match (net, net_config_path) {
+        (Some(net), _) => Ok(net.genesis_config().clone()),
+        (None, Some(path)) => GenesisConfig::load(path),
+        (None, None) => bail!("Please specify --net or --net-config-path."),
+    }
+}
+
+fn cost_table_diff(name: &str, current: &[GasCost], new: &[GasCost]) -> Vec<String> {
+    let mut diff = vec![];
+    for idx in 0..current.len().max(new.len()) {
+        match (current.get(idx), new.get(idx)) {
+            (Some(current), Some(new)) if current != new => {
+                diff.push(format!("{}[{}]: {:?} -> {:?}", name, idx, current, new))
+            }
+            (Some(current), None) => diff.push(format!("{}[{}]: {:?} -> None", name, idx, current)),
+            (None, Some(new)) => diff.push(format!("{}[{}]: None -> {:?}", name, idx, new)),
+            _ => {}
+        }
+    }
+    diff
+}
+
+/// Human readable diff of the gas entries changed from `current` to `new`.
+pub fn gas_schedule_diff(current: &CostTable, new: &CostTable) -> Vec<String> {
+    let mut diff = cost_table_diff(
+        "instruction",
+        &current.instruction_table,
+        &new.instruction_table,
+    );
+    diff.extend(cost_table_diff(
+        "native",
+        &current.native_table,
+        &new.native_table,
+    ));
+    if current.gas_constants != new.gas_constants {
+        diff.push(format!(
+            "gas_constants: {:?} -> {:?}",
+            current.gas_constants, new.gas_constants
+        ));
+    }
+    diff
+}
+
+/// Mirror of the Move `Dao::Proposal<Token, Action>` resource, to decode it with BCS.
+/// `on_chain_resource::dao::Proposal` requires the action to be a `ProposalAction`, which the
+/// on chain config update is not.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Proposal<Action> {
+    pub id: u64,
+    pub proposer: AccountAddress,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub for_votes: u128,
+    pub against_votes: u128,
+    pub eta: u64,
+    pub action_delay: u64,
+    pub quorum_votes: u128,
+    /// None if the action is already extracted.
+    pub action: Option<Action>,
+}
+
+/// An on chain config which is the action of its upgrade proposal.
+pub trait ConfigProposalAction: Sized {
+    /// The Move layout of the config. `OnChainConfigUpdate` only wraps the config, so it is also
+    /// the layout of the action.
+    type Action: DeserializeOwned;
+
+    fn from_action(action: Self::Action) -> Result<Self>;
+}
+
+impl ConfigProposalAction for VMConfig {
+    type Action = VMConfigInner;
+
+    fn from_action(action: Self::Action) -> Result<Self> {
+        action.as_vm_config()
+    }
+}
+
+impl ConfigProposalAction for ConsensusConfig {
+    type Action = ConsensusConfig;
+
+    fn from_action(action: Self::Action) -> Result<Self> {
+        Ok(action)
+    }
+}
+
+/// Decode the config in the action of a raw on chain config proposal resource, None if the action
+/// is already extracted.
+pub fn decode_proposal_config<C>(raw_proposal: &[u8]) -> Result<Option<C>>
+where
+    C: ConfigProposalAction,
+{
+    let proposal: Proposal<C::Action> = bcs_ext::from_bytes(raw_proposal)?;
+    proposal.action.map(C::from_action).transpose()
+}
+
+/// Check the struct tag is a `Dao::Proposal` with the given action type.
+pub fn is_proposal_of_action(struct_tag: &StructTag, action_type: &TypeTag) -> bool {
+    struct_tag.address == genesis_address()
+        && struct_tag.module.as_str() == "Dao"
+        && struct_tag.name.as_str() == "Proposal"
+        && struct_tag.type_params.get(1) == Some(action_type)
+}
+
+/// Dry run the on chain config proposal, decode the config in the proposal action from the
+/// dry run write set and print it as json.
+pub fn simulate_config_proposal<C>(
+    cli_state: &CliState,
+    mut txn_opts: TransactionOptions,
+    payload: TransactionPayload,
+    config_type: TypeTag,
+) -> Result<(ExecuteResultView, C)>
+where
+    C: ConfigProposalAction + Serialize,
+{
+    txn_opts.dry_run = true;
+    txn_opts.unsigned_output = None;
+    let execute_result = cli_state.build_and_execute_transaction(txn_opts, payload)?;
+    let dry_run_output = execute_result
+        .dry_run_output
+        .as_ref()
+        .expect("dry run output should exist without unsigned-output.");
+    if !matches!(
+        dry_run_output.txn_output.status,
+        TransactionStatusView::Executed
+    ) {
+        bail!(
+            "proposal dry run failed, status: {:?}, explained: {:?}",
+            dry_run_output.txn_output.status,
+            dry_run_output.explained_status
+        );
+    }
+    let action_type = on_chain_config_update_type_tag(config_type);
+    let proposal = dry_run_output
+        .txn_output
+        .write_set
+        .iter()
+        .find_map(|output| {
+            let struct_tag = output.access_path.path.as_struct_tag()?;
+            if !is_proposal_of_action(struct_tag, &action_type) {
+                return None;
+            }
+            match output.value.as_ref() {
+                Some(WriteOpValueView::Resource(resource)) => Some(resource.raw.0.clone()),
+                _ => None,
+            }
+        })
+        .ok_or_else(|| format_err!("Can not find the proposal in the dry run write set."))?;
+    let config = decode_proposal_config::<C>(&proposal)?
+        .ok_or_else(|| format_err!("The proposal has no action."))?;
+    println!("{}", serde_json::to_string_pretty(&config)?);
+    Ok((execute_result, config))
+}
+
+/// Print the diff between the gas schedule in the proposal action and the target gas schedule.
+pub fn check_proposed_gas_schedule(proposed: &CostTable, target: &CostTable) {
+    let diff = gas_schedule_diff(proposed, target);
+    if diff.is_empty() {
+        eprintln!("proposed gas schedule is same as the target gas schedule.");
+    } else {
+        eprintln!("proposed gas schedule is different from the target gas schedule:");
+        for line in diff {
+            eprintln!("{}", line);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_state::CliState;
+use crate::dev::dev_helper;
+use crate::dev::sign_txn_helper::get_dao_config;
+use crate::view::ProposalScriptView;
+use crate::StarcoinOpt;
+use anyhow::{bail, Result};
+use scmd::{CommandAction, ExecContext};
+use starcoin_config::BuiltinNetworkID;
+use starcoin_crypto::HashValue;
+use starcoin_rpc_api::types::StrView;
+use starcoin_transaction_builder::build_vm_config_upgrade_proposal;
+use std::fs::File;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Export the VM config upgrade proposal script to a file for offline review,
+/// or verify an exported file against the same inputs.
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "vm-config-proposal-export",
+    alias = "vm_config_proposal_export"
+)]
+#[allow(clippy::upper_case_acronyms)]
+pub struct ExportVMConfigProposalOpt {
+    #[structopt(short = "n", name = "net", long = "net")]
+    /// The network id for copy config, conflicts with net-config-path
+    net: Option<BuiltinNetworkID>,
+
+    #[structopt(
+        name = "net-config-path",
+        long = "net-config-path",
+        parse(from_os_str),
+        conflicts_with("net")
+    )]
+    /// The genesis config json file for copy config, used when the network is not a builtin network
+    net_config_path: Option<PathBuf>,
+
+    #[structopt(name = "exec-delay", long = "exec-delay")]
+    /// The proposal exec delay in milliseconds, if absent, use the on chain dao min_action_delay.
+    /// Set it to make the exported file reproducible without the chain.
+    exec_delay: Option<u64>,
+
+    #[structopt(short = "o", name = "output", long = "output", parse(from_os_str))]
+    /// The file to write the proposal script json to.
+    output: Option<PathBuf>,
+
+    #[structopt(
+        name = "verify",
+        long = "verify",
+        parse(from_os_str),
+        conflicts_with("output")
+    )]
+    /// Verify the proposal script json file is same as the one built from the inputs.
+    verify: Option<PathBuf>,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+pub struct ExportVMConfigProposalCommand;
+
+impl CommandAction for ExportVMConfigProposalCommand {
+    type State = CliState;
+    type GlobalOpt = StarcoinOpt;
+    type Opt = ExportVMConfigProposalOpt;
+    type ReturnItem = ProposalScriptView;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let opt = ctx.opt();
+
+        let genesis_config =
+            dev_helper::load_genesis_config(opt.net, opt.net_config_path.as_ref())?;
+        let exec_delay = match opt.exec_delay {
+            Some(exec_delay) => exec_delay,
+            None => get_dao_config(ctx.state())?.min_action_delay,
+        };
+        let script_function =
+            build_vm_config_upgrade_proposal(genesis_config.vm_config, exec_delay);
+        let hash = HashValue::sha3_256_of(&bcs_ext::to_bytes(&script_function)?);
+        let view = ProposalScriptView {
+            module: StrView(script_function.module().clone()),
+            function: script_function.function().to_string(),
+            ty_args: script_function
+                .ty_args()
+                .iter()
+                .map(|ty| StrView(ty.clone()))
+                .collect(),
+            args: script_function
+                .args()
+                .iter()
+                .map(|arg| StrView(arg.clone()))
+                .collect(),
+            hash,
+        };
+
+        if let Some(output) = opt.output.as_ref() {
+            serde_json::to_writer_pretty(File::create(output)?, &view)?;
+        }
+        if let Some(verify) = opt.verify.as_ref() {
+            let exported: ProposalScriptView = serde_json::from_reader(File::open(verify)?)?;
+            if exported.hash != view.hash {
+                bail!(
+                    "Proposal hash mismatch, exported: {}, built from inputs: {}",
+                    exported.hash,
+                    view.hash
+                );
+            }
+            if exported != view {
+                bail!("Proposal hash matches but the script content is modified.");
+            }
+        }
+        Ok(view)
+    }
+}
@@ -1,38 +1,66 @@
 // Copyright (c) The Starcoin Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+pub use batch_proposal_cmd::*;
 pub use call_contract_cmd::*;
 pub use compile_cmd::*;
+pub use config_diff_cmd::*;
+pub use dao_destroy_proposal_cmd::*;
+pub use dao_execute_cmd::*;
+pub use dao_list_proposals_cmd::*;
+pub use dao_queue_cmd::*;
+pub use decode_payload_cmd::*;
 pub use deploy_cmd::*;
+pub use export_vm_config_proposal_cmd::*;
 pub use get_coin_cmd::*;
+pub use natives_list_cmd::*;
 pub use package_cmd::*;
+pub use proposal_forecast_cmd::*;
+pub use proposal_watch_cmd::*;
 pub use subscribe_cmd::*;
+pub use upgrade_consensus_config_proposal_cmd::*;
+pub use upgrade_gas_schedule_proposal_cmd::*;
 pub use upgrade_module_exe_cmd::*;
 pub use upgrade_module_plan_cmd::*;
 pub use upgrade_module_proposal_cmd::*;
 pub use upgrade_module_queue_cmd::*;
 pub use upgrade_vm_config_proposal_cmd::*;
+pub use verify_proposal_cmd::*;
 
+mod batch_proposal_cmd;
 pub(crate) mod call_api_cmd;
 mod call_contract_cmd;
 mod compile_cmd;
+mod config_diff_cmd;
+mod dao_destroy_proposal_cmd;
+mod dao_execute_cmd;
+mod dao_list_proposals_cmd;
+mod dao_queue_cmd;
+mod decode_payload_cmd;
 mod deploy_cmd;
 pub(crate) mod dev_helper;
+mod export_vm_config_proposal_cmd;
 pub(crate) mod gen_block_cmd;
 mod get_coin_cmd;
 pub(crate) mod log_cmd;
 pub(crate) mod move_explain;
+mod natives_list_cmd;
 mod package_cmd;
 pub(crate) mod panic_cmd;
+mod proposal_forecast_cmd;
+mod proposal_watch_cmd;
 pub(crate) mod resolve_cmd;
 pub(crate) mod sign_txn_helper;
 pub(crate) mod sleep_cmd;
 mod subscribe_cmd;
+mod upgrade_consensus_config_proposal_cmd;
+mod upgrade_gas_schedule_proposal_cmd;
 mod upgrade_module_exe_cmd;
 mod upgrade_module_plan_cmd;
 mod upgrade_module_proposal_cmd;
 mod upgrade_module_queue_cmd;
 mod upgrade_vm_config_proposal_cmd;
+mod verify_proposal_cmd;
 
 #[cfg(test)]
 mod tests;
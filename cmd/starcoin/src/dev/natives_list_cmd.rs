@@ -0,0 +1,33 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_state::CliState;
+use crate::StarcoinOpt;
+use anyhow::Result;
+use scmd::{CommandAction, ExecContext};
+use starcoin_rpc_api::types::NativesView;
+use structopt::StructOpt;
+
+/// List the native functions registered by the connected node's VM, with their address, module
+/// and function name, and flag the natives only registered in `testing` feature builds.
+/// Also show whether the node is built with the `testing` feature and whether the debug natives
+/// are enabled. Diff the output of two nodes to find a native mismatch.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "natives-list", alias = "natives_list")]
+pub struct NativesListOpt {}
+
+pub struct NativesListCommand;
+
+impl CommandAction for NativesListCommand {
+    type State = CliState;
+    type GlobalOpt = StarcoinOpt;
+    type Opt = NativesListOpt;
+    type ReturnItem = NativesView;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        ctx.state().client().node_natives()
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_state::CliState;
+use crate::dev::dao_list_proposals_cmd::{is_proposal_type, json_u128, json_u64};
+use crate::dev::sign_txn_helper::{
+    get_dao_config, get_proposal_state, proposal_state_name, ACTIVE, AGREED, DEFEATED, EXECUTABLE,
+    EXTRACTED, PENDING, QUEUED,
+};
+use crate::view::ProposalForecastView;
+use crate::StarcoinOpt;
+use anyhow::{format_err, Result};
+use scmd::{CommandAction, ExecContext};
+use starcoin_vm_types::account_address::AccountAddress;
+use structopt::StructOpt;
+
+/// Forecast the outcome of a dao proposal with the current votes.
+/// Show whether the proposal meets the quorum, whether it would pass if voting ended now,
+/// and how many more for votes are needed.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "proposal-forecast", alias = "proposal_forecast")]
+pub struct ProposalForecastOpt {
+    #[structopt(short = "a", name = "proposer-address", long = "proposer_address")]
+    /// the account address of the proposer, if absent, use default account.
+    proposer_address: Option<AccountAddress>,
+
+    #[structopt(short = "i", name = "proposal-id", long = "proposal-id")]
+    /// the proposal id.
+    proposal_id: u64,
+}
+
+pub struct ProposalForecastCommand;
+
+impl CommandAction for ProposalForecastCommand {
+    type State = CliState;
+    type GlobalOpt = StarcoinOpt;
+    type Opt = ProposalForecastOpt;
+    type ReturnItem = ProposalForecastView;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let opt = ctx.opt();
+        let proposer = match opt.proposer_address {
+            Some(address) => address,
+            None => ctx.state().default_account()?.address,
+        };
+
+        let all_resources = ctx
+            .state()
+            .client()
+            .state_list_resource(proposer, true, None)?;
+        let (resource_type, proposal_json) = all_resources
+            .resources
+            .into_iter()
+            .filter(|(resource_type, _)| is_proposal_type(&resource_type.0))
+            .filter_map(|(resource_type, resource)| Some((resource_type, resource.json?.0)))
+            .find(|(_, proposal_json)| json_u64(proposal_json, "id").ok() == Some(opt.proposal_id))
+            .ok_or_else(|| {
+                format_err!(
+                    "Can not find proposal {} of proposer {}, it may be destroyed.",
+                    opt.proposal_id,
+                    proposer
+                )
+            })?;
+        let state = get_proposal_state(
+            ctx.state(),
+            resource_type.0.type_params[0].clone(),
+            resource_type.0.type_params[1].clone(),
+            proposer,
+            opt.proposal_id,
+        )?;
+        let for_votes = json_u128(&proposal_json, "for_votes")?;
+        let against_votes = json_u128(&proposal_json, "against_votes")?;
+        let quorum_votes = json_u128(&proposal_json, "quorum_votes")?;
+        let (outcome, votes_needed) = forecast(state, for_votes, against_votes, quorum_votes);
+
+        Ok(ProposalForecastView {
+            proposer,
+            id: opt.proposal_id,
+            state: proposal_state_name(state).to_string(),
+            for_votes,
+            against_votes,
+            quorum_votes,
+            voting_quorum_rate: get_dao_config(ctx.state())?.voting_quorum_rate,
+            meets_quorum: for_votes >= quorum_votes,
+            outcome: outcome.to_string(),
+            votes_needed,
+        })
+    }
+}
+
+/// Return the outcome and the for votes still needed to pass.
+/// As `Dao::proposal_state`, a proposal passes if its for votes are more than the against votes
+/// and reach the quorum votes.
+fn forecast(
+    state: u64,
+    for_votes: u128,
+    against_votes: u128,
+    quorum_votes: u128,
+) -> (&'static str, u128) {
+    match state {
+        DEFEATED => ("failed", 0),
+        AGREED | QUEUED | EXECUTABLE | EXTRACTED => ("passed", 0),
+        PENDING | ACTIVE => {
+            let votes_needed = quorum_votes
+                .saturating_sub(for_votes)
+                .max(against_votes.saturating_add(1).saturating_sub(for_votes));
+            if votes_needed == 0 {
+                ("would pass", 0)
+            } else {
+                ("would fail", votes_needed)
+            }
+        }
+        _ => ("unknown", 0),
+    }
+}
+
+#[test]
+fn test_forecast() {
+    // not reach the quorum.
+    assert_eq!(forecast(ACTIVE, 30, 10, 50), ("would fail", 20));
+    // reach the quorum, but not more than the against votes.
+    assert_eq!(forecast(ACTIVE, 60, 80, 50), ("would fail", 21));
+    assert_eq!(forecast(ACTIVE, 60, 60, 50), ("would fail", 1));
+    assert_eq!(forecast(ACTIVE, 60, 10, 50), ("would pass", 0));
+    assert_eq!(forecast(PENDING, 0, 0, 50), ("would fail", 50));
+    assert_eq!(forecast(DEFEATED, 30, 10, 50), ("failed", 0));
+    assert_eq!(forecast(QUEUED, 60, 10, 50), ("passed", 0));
+}
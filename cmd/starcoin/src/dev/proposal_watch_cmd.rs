@@ -0,0 +1,196 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_state::CliState;
+use crate::dev::dao_list_proposals_cmd::{is_proposal_type, json_u64};
+use crate::dev::sign_txn_helper::{
+    get_proposal_state, proposal_state_name, ACTIVE, AGREED, DEFEATED, EXECUTABLE, EXTRACTED,
+    PENDING, QUEUED,
+};
+use crate::StarcoinOpt;
+use anyhow::{ensure, format_err, Result};
+use scmd::{CommandAction, ExecContext};
+use serde_json::Value;
+use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::language_storage::StructTag;
+use std::thread::sleep;
+use std::time::Duration;
+use structopt::StructOpt;
+
+/// Watch a dao proposal, print a line each time its state changes.
+/// Exit when the proposal is defeated, executable or extracted, as executable proposals only
+/// move on by a `dev dao-execute` transaction.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "proposal-watch", alias = "proposal_watch")]
+pub struct ProposalWatchOpt {
+    #[structopt(short = "a", name = "proposer-address", long = "proposer_address")]
+    /// the account address of the proposer, if absent, use default account.
+    proposer_address: Option<AccountAddress>,
+
+    #[structopt(short = "i", name = "proposal-id", long = "proposal-id")]
+    /// the proposal id.
+    proposal_id: u64,
+
+    #[structopt(name = "interval", long = "interval", default_value = "3")]
+    /// the interval in seconds to poll the proposal state, at least 1.
+    interval: u64,
+}
+
+pub struct ProposalWatchCommand;
+
+impl CommandAction for ProposalWatchCommand {
+    type State = CliState;
+    type GlobalOpt = StarcoinOpt;
+    type Opt = ProposalWatchOpt;
+    type ReturnItem = ();
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let opt = ctx.opt();
+        ensure!(opt.interval >= 1, "interval should be at least 1 second.");
+        let proposer = match opt.proposer_address {
+            Some(address) => address,
+            None => ctx.state().default_account()?.address,
+        };
+        let (proposal_type, _) = find_proposal(ctx.state(), proposer, opt.proposal_id)?;
+
+        let mut tracker = StateTracker::default();
+        loop {
+            let state = get_proposal_state(
+                ctx.state(),
+                proposal_type.type_params[0].clone(),
+                proposal_type.type_params[1].clone(),
+                proposer,
+                opt.proposal_id,
+            )?;
+            if let Some(transition) = tracker.observe(state) {
+                let (_, proposal_json) = find_proposal(ctx.state(), proposer, opt.proposal_id)?;
+                println!(
+                    "proposal {}: {}, {}",
+                    opt.proposal_id,
+                    transition,
+                    phase_hint(state, &proposal_json)?
+                );
+            }
+            if is_terminal(state) {
+                return Ok(());
+            }
+            sleep(Duration::from_secs(opt.interval));
+        }
+    }
+}
+
+fn find_proposal(
+    cli_state: &CliState,
+    proposer: AccountAddress,
+    proposal_id: u64,
+) -> Result<(StructTag, Value)> {
+    cli_state
+        .client()
+        .state_list_resource(proposer, true, None)?
+        .resources
+        .into_iter()
+        .filter(|(resource_type, _)| is_proposal_type(&resource_type.0))
+        .filter_map(|(resource_type, resource)| Some((resource_type.0, resource.json?.0)))
+        .find(|(_, proposal_json)| json_u64(proposal_json, "id").ok() == Some(proposal_id))
+        .ok_or_else(|| {
+            format_err!(
+                "Can not find proposal {} of proposer {}, it may be destroyed.",
+                proposal_id,
+                proposer
+            )
+        })
+}
+
+fn is_terminal(state: u64) -> bool {
+    matches!(state, DEFEATED | EXECUTABLE | EXTRACTED)
+}
+
+/// Describe when the proposal leaves the state, the timestamps are in milliseconds.
+/// Only the proposal's own fields are used, the DaoConfig may have changed since it was created.
+fn phase_hint(state: u64, proposal_json: &Value) -> Result<String> {
+    Ok(match state {
+        PENDING => format!(
+            "voting starts at {}",
+            json_u64(proposal_json, "start_time")?
+        ),
+        ACTIVE => format!("voting ends at {}", json_u64(proposal_json, "end_time")?),
+        AGREED => format!(
+            "waiting to be queued, then executable after action_delay({} ms)",
+            json_u64(proposal_json, "action_delay")?
+        ),
+        QUEUED => format!("executable at {}", json_u64(proposal_json, "eta")?),
+        EXECUTABLE => "waiting to be executed".to_string(),
+        _ => "terminated".to_string(),
+    })
+}
+
+/// Keep the last polled state, so each state change is reported once.
+#[derive(Default)]
+struct StateTracker {
+    last: Option<u64>,
+}
+
+impl StateTracker {
+    /// Return the transition if the state changed, the first polled state is always reported.
+    fn observe(&mut self, state: u64) -> Option<String> {
+        if self.last == Some(state) {
+            return None;
+        }
+        let transition = match self.last {
+            Some(last) => format!(
+                "{} -> {}",
+                proposal_state_name(last),
+                proposal_state_name(state)
+            ),
+            None => proposal_state_name(state).to_string(),
+        };
+        self.last = Some(state);
+        Some(transition)
+    }
+}
+
+#[test]
+fn test_state_tracker() {
+    let mut tracker = StateTracker::default();
+    let transitions = vec![
+        PENDING, PENDING, ACTIVE, ACTIVE, ACTIVE, AGREED, QUEUED, QUEUED, EXECUTABLE,
+    ]
+    .into_iter()
+    .filter_map(|state| tracker.observe(state))
+    .collect::<Vec<_>>();
+    assert_eq!(
+        transitions,
+        vec![
+            "pending",
+            "pending -> active",
+            "active -> agreed",
+            "agreed -> queued",
+            "queued -> executable",
+        ]
+    );
+    assert!(is_terminal(EXECUTABLE));
+    assert!(is_terminal(DEFEATED));
+    assert!(!is_terminal(QUEUED));
+}
+
+#[test]
+fn test_phase_hint() {
+    let proposal_json = serde_json::json!({
+        "id": 0,
+        "start_time": 1000,
+        "end_time": 2000,
+        "eta": 0,
+        "action_delay": 3000,
+    });
+    assert_eq!(
+        phase_hint(AGREED, &proposal_json).unwrap(),
+        "waiting to be queued, then executable after action_delay(3000 ms)"
+    );
+    assert_eq!(
+        phase_hint(ACTIVE, &proposal_json).unwrap(),
+        "voting ends at 2000"
+    );
+}
@@ -3,9 +3,26 @@
 
 use crate::cli_state::CliState;
 use anyhow::{format_err, Result};
+use serde_json::Value;
+use starcoin_rpc_api::types::{ContractCall, FunctionIdView, TransactionArgumentView, TypeTagView};
 use starcoin_rpc_client::StateRootOption;
 use starcoin_state_api::StateReaderExt;
-use starcoin_vm_types::on_chain_config::DaoConfig;
+use starcoin_types::transaction::TransactionArgument;
+use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::account_config::genesis_address;
+use starcoin_vm_types::identifier::Identifier;
+use starcoin_vm_types::language_storage::{StructTag, TypeTag};
+use starcoin_vm_types::on_chain_config::{DaoConfig, VMConfig};
+use std::str::FromStr;
+
+/// Proposal states, keep same as the constants in Dao.move.
+pub const PENDING: u64 = 1;
+pub const ACTIVE: u64 = 2;
+pub const DEFEATED: u64 = 3;
+pub const AGREED: u64 = 4;
+pub const QUEUED: u64 = 5;
+pub const EXECUTABLE: u64 = 6;
+pub const EXTRACTED: u64 = 7;
 
 pub fn get_dao_config(cli_state: &CliState) -> Result<DaoConfig> {
     let client = cli_state.client();
@@ -14,3 +31,72 @@ pub fn get_dao_config(cli_state: &CliState) -> Result<DaoConfig> {
         .get_on_chain_config::<DaoConfig>()?
         .ok_or_else(|| format_err!("DaoConfig not exist on chain."))
 }
+
+pub fn get_vm_config(cli_state: &CliState) -> Result<VMConfig> {
+    let client = cli_state.client();
+    let chain_state_reader = client.state_reader(StateRootOption::Latest)?;
+    chain_state_reader
+        .get_on_chain_config::<VMConfig>()?
+        .ok_or_else(|| format_err!("VMConfig not exist on chain."))
+}
+
+/// Get the proposal state by call `0x1::Dao::proposal_state`.
+pub fn get_proposal_state(
+    cli_state: &CliState,
+    token_type: TypeTag,
+    action_type: TypeTag,
+    proposer_address: AccountAddress,
+    proposal_id: u64,
+) -> Result<u64> {
+    let call = ContractCall {
+        function_id: FunctionIdView::from_str("0x1::Dao::proposal_state")?,
+        type_args: vec![
+            TypeTagView::from(token_type),
+            TypeTagView::from(action_type),
+        ],
+        args: vec![
+            TransactionArgumentView::from(TransactionArgument::Address(proposer_address)),
+            TransactionArgumentView::from(TransactionArgument::U64(proposal_id)),
+        ],
+    };
+    cli_state
+        .client()
+        .contract_call(call)?
+        .get(0)
+        .and_then(|value| value.0.as_u64())
+        .ok_or_else(|| format_err!("Get proposal state fail."))
+}
+
+/// Get the decoded `Dao::Proposal` resource of the proposer.
+pub fn get_proposal_json(
+    cli_state: &CliState,
+    token_type: TypeTag,
+    action_type: TypeTag,
+    proposer_address: AccountAddress,
+) -> Result<Value> {
+    let proposal_type = StructTag {
+        address: genesis_address(),
+        module: Identifier::new("Dao")?,
+        name: Identifier::new("Proposal")?,
+        type_params: vec![token_type, action_type],
+    };
+    cli_state
+        .client()
+        .state_get_resource(proposer_address, proposal_type, true, None)?
+        .and_then(|resource| resource.json)
+        .map(|json| json.0)
+        .ok_or_else(|| format_err!("Proposal not exist under {}.", proposer_address))
+}
+
+pub fn proposal_state_name(state: u64) -> &'static str {
+    match state {
+        PENDING => "pending",
+        ACTIVE => "active",
+        DEFEATED => "defeated",
+        AGREED => "agreed",
+        QUEUED => "queued",
+        EXECUTABLE => "executable",
+        EXTRACTED => "extracted",
+        _ => "unknown",
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_state::CliState;
+use crate::dev::dev_helper;
+use crate::dev::sign_txn_helper::get_dao_config;
+use crate::view::{ExecuteResultView, TransactionOptions};
+use crate::StarcoinOpt;
+use anyhow::Result;
+use scmd::{CommandAction, ExecContext};
+use starcoin_config::BuiltinNetworkID;
+use starcoin_transaction_builder::build_consensus_config_upgrade_proposal;
+use starcoin_vm_types::on_chain_config::{consensus_config_type_tag, ConsensusConfig};
+use starcoin_vm_types::transaction::TransactionPayload;
+use structopt::StructOpt;
+
+/// Submit a consensus config upgrade proposal
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "consensus-config-proposal",
+    alias = "consensus_config_proposal"
+)]
+pub struct UpgradeConsensusConfigProposalOpt {
+    #[structopt(flatten)]
+    transaction_opts: TransactionOptions,
+
+    #[structopt(short = "n", name = "net", long = "net")]
+    /// The network id for copy config
+    net: BuiltinNetworkID,
+
+    #[structopt(long = "output-json")]
+    /// print the proposal payload as json before execute it, use with --dry-run to only review it.
+    output_json: bool,
+
+    #[structopt(long = "simulate")]
+    /// dry run the proposal, print the config in the proposal action and compare it with the network's config.
+    /// Only the proposal is dry run, the config is not set on chain until the proposal is executed.
+    simulate: bool,
+}
+
+pub struct UpgradeConsensusConfigProposalCommand;
+
+impl CommandAction for UpgradeConsensusConfigProposalCommand {
+    type State = CliState;
+    type GlobalOpt = StarcoinOpt;
+    type Opt = UpgradeConsensusConfigProposalOpt;
+    type ReturnItem = ExecuteResultView;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let opt = ctx.opt();
+
+        let genesis_config = opt.net.genesis_config().clone();
+        let min_action_delay = get_dao_config(ctx.state())?.min_action_delay;
+        let consensus_config_upgrade_proposal = build_consensus_config_upgrade_proposal(
+            genesis_config.consensus_config.clone(),
+            min_action_delay,
+        );
+        let payload = TransactionPayload::ScriptFunction(consensus_config_upgrade_proposal);
+        if opt.output_json {
+            dev_helper::print_payload_json(ctx.state(), &payload)?;
+        }
+        if opt.simulate {
+            let (execute_result, consensus_config) =
+                dev_helper::simulate_config_proposal::<ConsensusConfig>(
+                    ctx.state(),
+                    opt.transaction_opts.clone(),
+                    payload,
+                    consensus_config_type_tag(),
+                )?;
+            if consensus_config == genesis_config.consensus_config {
+                eprintln!("proposed consensus config is same as the target consensus config.");
+            } else {
+                eprintln!(
+                    "proposed consensus config is different from the target consensus config: {:?}",
+                    genesis_config.consensus_config
+                );
+            }
+            return Ok(execute_result);
+        }
+        ctx.state()
+            .build_and_execute_transaction(opt.transaction_opts.clone(), payload)
+    }
+}
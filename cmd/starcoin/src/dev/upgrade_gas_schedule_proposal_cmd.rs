@@ -0,0 +1,88 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_state::CliState;
+use crate::dev::dev_helper;
+use crate::dev::sign_txn_helper::{get_dao_config, get_vm_config};
+use crate::view::{ExecuteResultView, TransactionOptions};
+use crate::StarcoinOpt;
+use anyhow::Result;
+use scmd::{CommandAction, ExecContext};
+use starcoin_config::BuiltinNetworkID;
+use starcoin_transaction_builder::build_gas_schedule_upgrade_proposal;
+use starcoin_vm_types::on_chain_config::{vm_config_type_tag, VMConfig};
+use starcoin_vm_types::transaction::TransactionPayload;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Submit a gas schedule upgrade proposal
+#[derive(Debug, StructOpt)]
+#[structopt(name = "gas-schedule-proposal", alias = "gas_schedule_proposal")]
+pub struct UpgradeGasScheduleProposalOpt {
+    #[structopt(flatten)]
+    transaction_opts: TransactionOptions,
+
+    #[structopt(short = "n", name = "net", long = "net")]
+    /// The network id for copy gas schedule, conflicts with net-config-path
+    net: Option<BuiltinNetworkID>,
+
+    #[structopt(
+        name = "net-config-path",
+        long = "net-config-path",
+        parse(from_os_str),
+        conflicts_with("net")
+    )]
+    /// The genesis config json file for copy gas schedule, used when the network is not a builtin network
+    net_config_path: Option<PathBuf>,
+
+    #[structopt(long = "simulate")]
+    /// dry run the proposal, print the config in the proposal action and compare it with the network's config.
+    /// Only the proposal is dry run, the config is not set on chain until the proposal is executed.
+    simulate: bool,
+}
+
+pub struct UpgradeGasScheduleProposalCommand;
+
+impl CommandAction for UpgradeGasScheduleProposalCommand {
+    type State = CliState;
+    type GlobalOpt = StarcoinOpt;
+    type Opt = UpgradeGasScheduleProposalOpt;
+    type ReturnItem = ExecuteResultView;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let opt = ctx.opt();
+
+        let genesis_config =
+            dev_helper::load_genesis_config(opt.net, opt.net_config_path.as_ref())?;
+        let gas_schedule = genesis_config.vm_config.gas_schedule;
+        if opt.transaction_opts.dry_run {
+            let current_gas_schedule = get_vm_config(ctx.state())?.gas_schedule;
+            let diff = dev_helper::gas_schedule_diff(&current_gas_schedule, &gas_schedule);
+            if diff.is_empty() {
+                eprintln!("gas schedule is same as the on chain gas schedule.");
+            }
+            for line in diff {
+                eprintln!("{}", line);
+            }
+        }
+        let min_action_delay = get_dao_config(ctx.state())?.min_action_delay;
+        let gas_schedule_upgrade_proposal =
+            build_gas_schedule_upgrade_proposal(gas_schedule.clone(), min_action_delay);
+        let payload = TransactionPayload::ScriptFunction(gas_schedule_upgrade_proposal);
+        if opt.simulate {
+            let (execute_result, vm_config) = dev_helper::simulate_config_proposal::<VMConfig>(
+                ctx.state(),
+                opt.transaction_opts.clone(),
+                payload,
+                vm_config_type_tag(),
+            )?;
+            dev_helper::check_proposed_gas_schedule(&vm_config.gas_schedule, &gas_schedule);
+            return Ok(execute_result);
+        }
+        ctx.state()
+            .build_and_execute_transaction(opt.transaction_opts.clone(), payload)
+    }
+}
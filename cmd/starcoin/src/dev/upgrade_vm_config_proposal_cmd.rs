@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::cli_state::CliState;
+use crate::dev::dev_helper;
 use crate::dev::sign_txn_helper::get_dao_config;
 use crate::view::{ExecuteResultView, TransactionOptions};
 use crate::StarcoinOpt;
@@ -9,7 +10,9 @@ use anyhow::Result;
 use scmd::{CommandAction, ExecContext};
 use starcoin_config::BuiltinNetworkID;
 use starcoin_transaction_builder::build_vm_config_upgrade_proposal;
+use starcoin_vm_types::on_chain_config::{vm_config_type_tag, VMConfig};
 use starcoin_vm_types::transaction::TransactionPayload;
+use std::path::PathBuf;
 use structopt::StructOpt;
 
 /// Submit a VM config upgrade proposal
@@ -21,8 +24,26 @@ pub struct UpgradeVMConfigProposalOpt {
     transaction_opts: TransactionOptions,
 
     #[structopt(short = "n", name = "net", long = "net")]
-    /// The network id for copy config
-    net: BuiltinNetworkID,
+    /// The network id for copy config, conflicts with net-config-path
+    net: Option<BuiltinNetworkID>,
+
+    #[structopt(
+        name = "net-config-path",
+        long = "net-config-path",
+        parse(from_os_str),
+        conflicts_with("net")
+    )]
+    /// The genesis config json file for copy config, used when the network is not a builtin network
+    net_config_path: Option<PathBuf>,
+
+    #[structopt(long = "output-json")]
+    /// print the proposal payload as json before execute it, use with --dry-run to only review it.
+    output_json: bool,
+
+    #[structopt(long = "simulate")]
+    /// dry run the proposal, print the config in the proposal action and compare it with the network's config.
+    /// Only the proposal is dry run, the config is not set on chain until the proposal is executed.
+    simulate: bool,
 }
 
 #[allow(clippy::upper_case_acronyms)]
@@ -40,13 +61,32 @@ impl CommandAction for UpgradeVMConfigProposalCommand {
     ) -> Result<Self::ReturnItem> {
         let opt = ctx.opt();
 
-        let genesis_config = opt.net.genesis_config().clone();
+        let genesis_config =
+            dev_helper::load_genesis_config(opt.net, opt.net_config_path.as_ref())?;
         let min_action_delay = get_dao_config(ctx.state())?.min_action_delay;
+        if opt.transaction_opts.dry_run {
+            eprintln!("proposal min_action_delay: {}", min_action_delay);
+        }
         let vm_config_upgrade_proposal =
-            build_vm_config_upgrade_proposal(genesis_config.vm_config, min_action_delay);
-        ctx.state().build_and_execute_transaction(
-            opt.transaction_opts.clone(),
-            TransactionPayload::ScriptFunction(vm_config_upgrade_proposal),
-        )
+            build_vm_config_upgrade_proposal(genesis_config.vm_config.clone(), min_action_delay);
+        let payload = TransactionPayload::ScriptFunction(vm_config_upgrade_proposal);
+        if opt.output_json {
+            dev_helper::print_payload_json(ctx.state(), &payload)?;
+        }
+        if opt.simulate {
+            let (execute_result, vm_config) = dev_helper::simulate_config_proposal::<VMConfig>(
+                ctx.state(),
+                opt.transaction_opts.clone(),
+                payload,
+                vm_config_type_tag(),
+            )?;
+            dev_helper::check_proposed_gas_schedule(
+                &vm_config.gas_schedule,
+                &genesis_config.vm_config.gas_schedule,
+            );
+            return Ok(execute_result);
+        }
+        ctx.state()
+            .build_and_execute_transaction(opt.transaction_opts.clone(), payload)
     }
 }
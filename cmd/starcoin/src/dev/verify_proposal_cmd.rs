@@ -0,0 +1,217 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_state::CliState;
+use crate::dev::dao_list_proposals_cmd::json_u64;
+use crate::dev::{dev_helper, ProposalKind};
+use crate::view::ProposalVerifyView;
+use crate::StarcoinOpt;
+use anyhow::{ensure, format_err, Result};
+use scmd::{CommandAction, ExecContext};
+use serde_json::Value;
+use starcoin_config::{BuiltinNetworkID, GenesisConfig};
+use starcoin_crypto::HashValue;
+use starcoin_transaction_builder::{
+    build_consensus_config_upgrade_proposal, build_gas_schedule_upgrade_proposal,
+    build_vm_config_upgrade_proposal, on_chain_config_update_type_tag,
+};
+use starcoin_vm_types::account_address::AccountAddress;
+use starcoin_vm_types::on_chain_config::{ConsensusConfig, VMConfig};
+use starcoin_vm_types::transaction::ScriptFunction;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Verify a config upgrade proposal on chain does what its proposer claims.
+/// Rebuild the proposal script function from the network's genesis config and from the
+/// on chain proposal action, and check they are the same byte for byte.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "verify-proposal", alias = "verify_proposal")]
+pub struct VerifyProposalOpt {
+    #[structopt(short = "a", name = "proposer-address", long = "proposer_address")]
+    /// the account address of the proposer, if absent, use default account.
+    proposer_address: Option<AccountAddress>,
+
+    #[structopt(short = "i", name = "proposal-id", long = "proposal-id")]
+    /// the proposal id.
+    proposal_id: u64,
+
+    #[structopt(name = "kind", long = "kind")]
+    /// The proposal kind, one of vm-config, gas-schedule and consensus-config.
+    kind: ProposalKind,
+
+    #[structopt(short = "n", name = "net", long = "net")]
+    /// The network id for the expected config, conflicts with net-config-path
+    net: Option<BuiltinNetworkID>,
+
+    #[structopt(
+        name = "net-config-path",
+        long = "net-config-path",
+        parse(from_os_str),
+        conflicts_with("net")
+    )]
+    /// The genesis config json file for the expected config, used when the network is not a builtin network
+    net_config_path: Option<PathBuf>,
+}
+
+pub struct VerifyProposalCommand;
+
+impl CommandAction for VerifyProposalCommand {
+    type State = CliState;
+    type GlobalOpt = StarcoinOpt;
+    type Opt = VerifyProposalOpt;
+    type ReturnItem = ProposalVerifyView;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let opt = ctx.opt();
+        let proposer = match opt.proposer_address {
+            Some(address) => address,
+            None => ctx.state().default_account()?.address,
+        };
+        let genesis_config =
+            dev_helper::load_genesis_config(opt.net, opt.net_config_path.as_ref())?;
+
+        let action_type = on_chain_config_update_type_tag(opt.kind.config_type_tag());
+        let resources = ctx
+            .state()
+            .client()
+            .state_list_resource(proposer, true, None)?;
+        let (proposal_json, raw_proposal) = resources
+            .resources
+            .into_iter()
+            .find_map(|(resource_type, resource)| {
+                if dev_helper::is_proposal_of_action(&resource_type.0, &action_type) {
+                    Some((resource.json?.0, resource.raw.0))
+                } else {
+                    None
+                }
+            })
+            .ok_or_else(|| {
+                format_err!(
+                    "Can not find the {} proposal of proposer {}.",
+                    opt.kind,
+                    proposer
+                )
+            })?;
+        let (expected, actual) = rebuild_script_functions(
+            opt.kind,
+            &proposal_json,
+            &raw_proposal,
+            opt.proposal_id,
+            &genesis_config,
+        )?;
+        let expected = bcs_ext::to_bytes(&expected)?;
+        let actual = bcs_ext::to_bytes(&actual)?;
+        Ok(ProposalVerifyView {
+            proposer,
+            id: opt.proposal_id,
+            expected: HashValue::sha3_256_of(&expected),
+            actual: HashValue::sha3_256_of(&actual),
+            matched: expected == actual,
+        })
+    }
+}
+
+/// Return the script functions rebuilt from the genesis config and from the proposal action.
+/// Both use the proposal's action delay, so only the config is compared.
+fn rebuild_script_functions(
+    kind: ProposalKind,
+    proposal_json: &Value,
+    raw_proposal: &[u8],
+    proposal_id: u64,
+    genesis_config: &GenesisConfig,
+) -> Result<(ScriptFunction, ScriptFunction)> {
+    let id = json_u64(proposal_json, "id")?;
+    ensure!(
+        id == proposal_id,
+        "The proposal id on chain is {}, not {}, it may be executed or replaced.",
+        id,
+        proposal_id
+    );
+    let delay = json_u64(proposal_json, "action_delay")?;
+    let extracted = || format_err!("The proposal action is already extracted.");
+    Ok(match kind {
+        ProposalKind::VMConfig => {
+            let vm_config = dev_helper::decode_proposal_config::<VMConfig>(raw_proposal)?
+                .ok_or_else(extracted)?;
+            (
+                build_vm_config_upgrade_proposal(genesis_config.vm_config.clone(), delay),
+                build_vm_config_upgrade_proposal(vm_config, delay),
+            )
+        }
+        ProposalKind::GasSchedule => {
+            let vm_config = dev_helper::decode_proposal_config::<VMConfig>(raw_proposal)?
+                .ok_or_else(extracted)?;
+            (
+                build_gas_schedule_upgrade_proposal(
+                    genesis_config.vm_config.gas_schedule.clone(),
+                    delay,
+                ),
+                build_gas_schedule_upgrade_proposal(vm_config.gas_schedule, delay),
+            )
+        }
+        ProposalKind::ConsensusConfig => {
+            let consensus_config =
+                dev_helper::decode_proposal_config::<ConsensusConfig>(raw_proposal)?
+                    .ok_or_else(extracted)?;
+            (
+                build_consensus_config_upgrade_proposal(
+                    genesis_config.consensus_config.clone(),
+                    delay,
+                ),
+                build_consensus_config_upgrade_proposal(consensus_config, delay),
+            )
+        }
+    })
+}
+
+#[test]
+fn test_rebuild_script_functions() {
+    let genesis_config = BuiltinNetworkID::Test.genesis_config();
+    let proposal_json = serde_json::json!({"id": 1, "action_delay": 60000});
+    let raw_proposal = |consensus_config: &ConsensusConfig| {
+        bcs_ext::to_bytes(&dev_helper::Proposal {
+            id: 1,
+            proposer: AccountAddress::random(),
+            start_time: 0,
+            end_time: 0,
+            for_votes: 0,
+            against_votes: 0,
+            eta: 0,
+            action_delay: 60000,
+            quorum_votes: 0,
+            action: Some(consensus_config.clone()),
+        })
+        .unwrap()
+    };
+    let rebuild = |raw_proposal: &[u8], proposal_id| {
+        rebuild_script_functions(
+            ProposalKind::ConsensusConfig,
+            &proposal_json,
+            raw_proposal,
+            proposal_id,
+            genesis_config,
+        )
+    };
+
+    let matched = raw_proposal(&genesis_config.consensus_config);
+    let (expected, actual) = rebuild(&matched, 1).unwrap();
+    assert_eq!(
+        bcs_ext::to_bytes(&expected).unwrap(),
+        bcs_ext::to_bytes(&actual).unwrap()
+    );
+
+    let mut tampered_config = genesis_config.consensus_config.clone();
+    tampered_config.base_reward_per_block += 1;
+    let tampered = raw_proposal(&tampered_config);
+    let (expected, actual) = rebuild(&tampered, 1).unwrap();
+    assert_ne!(
+        bcs_ext::to_bytes(&expected).unwrap(),
+        bcs_ext::to_bytes(&actual).unwrap()
+    );
+
+    // a different proposal id.
+    assert!(rebuild(&matched, 2).is_err());
+}
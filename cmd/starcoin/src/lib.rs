@@ -116,6 +116,20 @@ pub fn add_command(
                 .subcommand(dev::UpgradeModuleQueueCommand)
                 .subcommand(dev::UpgradeModuleExeCommand)
                 .subcommand(dev::UpgradeVMConfigProposalCommand)
+                .subcommand(dev::UpgradeConsensusConfigProposalCommand)
+                .subcommand(dev::UpgradeGasScheduleProposalCommand)
+                .subcommand(dev::DaoQueueCommand)
+                .subcommand(dev::DaoExecuteCommand)
+                .subcommand(dev::DaoDestroyProposalCommand)
+                .subcommand(dev::DaoListProposalsCommand)
+                .subcommand(dev::ConfigDiffCommand)
+                .subcommand(dev::ExportVMConfigProposalCommand)
+                .subcommand(dev::BatchProposalCommand)
+                .subcommand(dev::DecodePayloadCommand)
+                .subcommand(dev::VerifyProposalCommand)
+                .subcommand(dev::ProposalForecastCommand)
+                .subcommand(dev::ProposalWatchCommand)
+                .subcommand(dev::NativesListCommand)
                 .subcommand(dev::PackageCmd)
                 .subcommand(dev::CallContractCommand)
                 .subcommand(dev::resolve_cmd::ResolveCommand)
@@ -7,7 +7,7 @@ use starcoin_account_api::AccountInfo;
 use starcoin_crypto::HashValue;
 pub use starcoin_rpc_api::types::TransactionOutputView;
 use starcoin_rpc_api::types::{
-    DryRunOutputView, RawUserTransactionView, StrView, TransactionEventResponse,
+    DryRunOutputView, ModuleIdView, RawUserTransactionView, StrView, TransactionEventResponse,
     TransactionEventView, TransactionInfoView, TypeTagView,
 };
 use starcoin_types::account_address::AccountAddress;
@@ -60,6 +60,22 @@ pub struct TransactionOptions {
     #[structopt(long = "dry-run")]
     /// dry-run mode, only get transaction output, do not change chain state.
     pub dry_run: bool,
+
+    #[structopt(long = "gas-budget-estimate")]
+    /// dry-run the transaction first to estimate the gas used,
+    /// if max-gas-amount is absent, use the estimated gas with a margin as max gas amount.
+    pub gas_budget_estimate: bool,
+
+    #[structopt(long = "unsigned-output", parse(from_os_str))]
+    /// write the unsigned raw txn to the file for signing it offline, do not sign and submit it.
+    /// the sender is not required to be a local account, but the dry run needs its public key,
+    /// use `--skip-dry-run` for the sender without a local account.
+    /// the signed txn can be submitted by `account submit-txn`.
+    pub unsigned_output: Option<PathBuf>,
+
+    #[structopt(long = "skip-dry-run")]
+    /// do not dry run the txn before writing it to the unsigned-output file.
+    pub skip_dry_run: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -285,7 +301,8 @@ impl From<ContractEvent> for EventView {
 pub struct ExecuteResultView {
     pub raw_txn: RawUserTransactionView,
     pub raw_txn_hex: String,
-    pub dry_run_output: DryRunOutputView,
+    /// None if the dry run is skipped, only for the unsigned-output txn.
+    pub dry_run_output: Option<DryRunOutputView>,
     pub execute_output: Option<ExecutionOutputView>,
 }
 
@@ -293,7 +310,7 @@ impl ExecuteResultView {
     pub fn new(
         raw_txn: RawUserTransactionView,
         raw_txn_hex: String,
-        dry_run_output: DryRunOutputView,
+        dry_run_output: Option<DryRunOutputView>,
     ) -> Self {
         Self {
             raw_txn,
@@ -354,3 +371,63 @@ pub struct UncleInfo {
     pub uncle_parent_view: starcoin_rpc_api::types::BlockHeaderView,
     pub block_view: starcoin_rpc_api::types::BlockHeaderView,
 }
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProposalView {
+    pub proposer: AccountAddress,
+    pub id: u64,
+    pub token: TypeTagView,
+    pub action: TypeTagView,
+    pub state: String,
+    pub for_votes: u128,
+    pub against_votes: u128,
+    pub quorum_votes: u128,
+    /// milliseconds left in the current phase, only for pending, active and queued proposal.
+    pub remaining_time: Option<u64>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ProposalForecastView {
+    pub proposer: AccountAddress,
+    pub id: u64,
+    pub state: String,
+    pub for_votes: u128,
+    pub against_votes: u128,
+    pub quorum_votes: u128,
+    /// the current voting quorum rate of the dao config, in percent.
+    pub voting_quorum_rate: u8,
+    pub meets_quorum: bool,
+    /// passed, failed, or the outcome if voting ended now: would pass or would fail.
+    pub outcome: String,
+    /// more for votes needed to pass, 0 if the proposal would pass or has ended.
+    pub votes_needed: u128,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ConfigDiffView {
+    pub equal: bool,
+    /// The changed gas entries, from the on chain config to the genesis config.
+    pub diff: Vec<String>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ProposalVerifyView {
+    pub proposer: AccountAddress,
+    pub id: u64,
+    /// sha3_256 of the bcs encoded script function rebuilt from the genesis config.
+    pub expected: HashValue,
+    /// sha3_256 of the bcs encoded script function rebuilt from the on chain proposal action.
+    pub actual: HashValue,
+    pub matched: bool,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ProposalScriptView {
+    pub module: ModuleIdView,
+    pub function: String,
+    pub ty_args: Vec<TypeTagView>,
+    /// bcs encoded arguments.
+    pub args: Vec<StrView<Vec<u8>>>,
+    /// sha3_256 of the bcs encoded script function.
+    pub hash: HashValue,
+}
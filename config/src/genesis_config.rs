@@ -23,7 +23,7 @@ use starcoin_vm_types::gas_schedule::{
 };
 use starcoin_vm_types::genesis_config::{ChainId, ConsensusStrategy, StdlibVersion};
 use starcoin_vm_types::on_chain_config::{
-    init_cost_table, initial_instruction_table, initial_native_table, v1_native_table,
+    init_cost_table, initial_instruction_table, latest_native_table, v1_native_table,
     ConsensusConfig, DaoConfig, TransactionPublishOption, VMConfig, Version,
 };
 use starcoin_vm_types::on_chain_resource::Epoch;
@@ -783,7 +783,7 @@ pub static TEST_CONFIG: Lazy<GenesisConfig> = Lazy::new(|| {
         vm_config: VMConfig {
             gas_schedule: CostTable {
                 instruction_table: initial_instruction_table(),
-                native_table: initial_native_table(),
+                native_table: latest_native_table(),
                 gas_constants: TEST_GAS_CONSTANTS.clone(),
             },
         },
@@ -836,7 +836,7 @@ pub static DEV_CONFIG: Lazy<GenesisConfig> = Lazy::new(|| {
         vm_config: VMConfig {
             gas_schedule: CostTable {
                 instruction_table: initial_instruction_table(),
-                native_table: initial_native_table(),
+                native_table: latest_native_table(),
                 gas_constants: TEST_GAS_CONSTANTS.clone(),
             },
         },
@@ -41,6 +41,7 @@ mod sync_config;
 #[cfg(test)]
 mod tests;
 mod txpool_config;
+mod vm_runtime_config;
 
 use crate::stratum_config::StratumConfig;
 pub use api_config::{Api, ApiSet};
@@ -67,6 +68,7 @@ pub use starcoin_crypto::ed25519::genesis_key_pair;
 pub use starcoin_vm_types::time::{MockTimeService, RealTimeService, TimeService};
 pub use storage_config::{RocksdbConfig, StorageConfig, DEFAULT_CACHE_SIZE};
 pub use txpool_config::TxPoolConfig;
+pub use vm_runtime_config::VmRuntimeConfig;
 
 pub static CRATE_VERSION: &str = crate_version!();
 pub static GIT_VERSION: &str = git_version!(
@@ -215,6 +217,9 @@ pub struct StarcoinOpt {
     #[serde(default)]
     #[structopt(flatten)]
     pub stratum: StratumConfig,
+    #[serde(default)]
+    #[structopt(flatten)]
+    pub vm_runtime: VmRuntimeConfig,
 }
 
 impl std::fmt::Display for StarcoinOpt {
@@ -430,6 +435,8 @@ pub struct NodeConfig {
     pub logger: LoggerConfig,
     #[serde(default)]
     pub stratum: StratumConfig,
+    #[serde(default)]
+    pub vm_runtime: VmRuntimeConfig,
 }
 
 impl std::fmt::Display for NodeConfig {
@@ -497,7 +504,8 @@ impl NodeConfig {
         self.vault.merge_with_opt(opt, base.clone())?;
         self.metrics.merge_with_opt(opt, base.clone())?;
         self.logger.merge_with_opt(opt, base.clone())?;
-        self.stratum.merge_with_opt(opt, base)?;
+        self.stratum.merge_with_opt(opt, base.clone())?;
+        self.vm_runtime.merge_with_opt(opt, base)?;
         Ok(())
     }
 }
@@ -27,6 +27,7 @@ use std::num::NonZeroU32;
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 use structopt::StructOpt;
 
 pub static DEFAULT_NETWORK_PORT: u16 = 9840;
@@ -242,6 +243,19 @@ pub struct NetworkConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[structopt(name = "unsupported-protocols", long, use_delimiter = true)]
     pub unsupported_protocols: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[structopt(name = "allowed-peers", long, use_delimiter = true)]
+    /// Only connect to these peers, multi peer id should use ',' as delimiter.
+    /// The connections of other peers are closed once established, and they are not dialed.
+    /// If absent or empty, all peers are allowed.
+    pub allowed_peers: Option<Vec<PeerId>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[structopt(name = "keepalive-interval", long)]
+    /// Interval in seconds to ping the connected peers, the pings keep idle connections alive.
+    /// If absent, idle connections may be dropped by NAT.
+    pub keepalive_interval: Option<u64>,
 }
 
 impl NetworkConfig {
@@ -322,6 +336,10 @@ impl NetworkConfig {
         self.max_outgoing_peers.clone().unwrap_or(75)
     }
 
+    pub fn keepalive_interval(&self) -> Option<Duration> {
+        self.keepalive_interval.map(Duration::from_secs)
+    }
+
     pub fn node_name(&self) -> String {
         self.node_name.clone().unwrap_or_else(generate_node_name)
     }
@@ -442,6 +460,14 @@ impl ConfigModule for NetworkConfig {
             self.max_outgoing_peers = opt.network.max_outgoing_peers;
         }
 
+        if opt.network.allowed_peers.is_some() {
+            self.allowed_peers = opt.network.allowed_peers.clone();
+        }
+
+        if opt.network.keepalive_interval.is_some() {
+            self.keepalive_interval = opt.network.keepalive_interval;
+        }
+
         if opt.network.unsupported_protocols.is_some() {
             let mut protocols: HashSet<String> = self
                 .unsupported_protocols
@@ -0,0 +1,28 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{BaseConfig, ConfigModule, StarcoinOpt};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use structopt::StructOpt;
+
+#[derive(Clone, Default, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
+#[serde(deny_unknown_fields)]
+pub struct VmRuntimeConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[structopt(name = "enable-debug-natives", long)]
+    /// Register the real `Debug::print` and `Debug::print_stack_trace` natives, which print to
+    /// the stdout of the node. If not set, the natives are no-op except in `testing` builds.
+    /// The gas cost is the same either way.
+    pub enable_debug_natives: Option<bool>,
+}
+
+impl ConfigModule for VmRuntimeConfig {
+    fn merge_with_opt(&mut self, opt: &StarcoinOpt, _base: Arc<BaseConfig>) -> Result<()> {
+        if opt.vm_runtime.enable_debug_natives.is_some() {
+            self.enable_debug_natives = opt.vm_runtime.enable_debug_natives;
+        }
+        Ok(())
+    }
+}
@@ -4,11 +4,12 @@
 use anyhow::anyhow;
 use anyhow::Result;
 use logger::prelude::*;
+use starcoin_config::BuiltinNetworkID;
 use starcoin_executor::account::{create_account_txn_sent_as_association, peer_to_peer_txn};
 use starcoin_executor::{encode_create_account_script_function, validate_transaction, Account};
 use starcoin_transaction_builder::{
-    build_batch_script_function_same_amount, raw_peer_to_peer_txn, DEFAULT_EXPIRATION_TIME,
-    DEFAULT_MAX_GAS_AMOUNT,
+    build_batch_script_function_same_amount, build_consensus_config_upgrade_proposal,
+    raw_peer_to_peer_txn, DEFAULT_EXPIRATION_TIME, DEFAULT_MAX_GAS_AMOUNT,
 };
 use starcoin_types::identifier::Identifier;
 use starcoin_types::language_storage::ModuleId;
@@ -982,3 +983,39 @@ fn test_block_metadata() -> Result<()> {
 
     Ok(())
 }
+
+#[stest::test]
+fn test_build_consensus_config_upgrade_proposal() -> Result<()> {
+    let consensus_config = BuiltinNetworkID::Test
+        .genesis_config()
+        .consensus_config
+        .clone();
+    let exec_delay = 1000u64;
+    let script_function =
+        build_consensus_config_upgrade_proposal(consensus_config.clone(), exec_delay);
+
+    assert_eq!(
+        script_function.function().as_str(),
+        "propose_update_consensus_config"
+    );
+    let args = script_function.args();
+    assert_eq!(args.len(), 12);
+    assert_eq!(
+        bcs_ext::from_bytes::<u64>(&args[0])?,
+        consensus_config.uncle_rate_target
+    );
+    assert_eq!(
+        bcs_ext::from_bytes::<u128>(&args[2])?,
+        consensus_config.base_reward_per_block
+    );
+    assert_eq!(
+        bcs_ext::from_bytes::<u64>(&args[4])?,
+        consensus_config.epoch_block_count
+    );
+    assert_eq!(
+        bcs_ext::from_bytes::<u8>(&args[10])?,
+        consensus_config.strategy
+    );
+    assert_eq!(bcs_ext::from_bytes::<u64>(&args[11])?, exec_delay);
+    Ok(())
+}
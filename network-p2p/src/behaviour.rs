@@ -147,11 +147,16 @@ impl Behaviour {
         local_public_key: PublicKey,
         disco_config: DiscoveryConfig,
         request_response_protocols: Vec<request_responses::ProtocolConfig>,
+        keepalive_interval: Option<Duration>,
     ) -> Result<Self, request_responses::RegisterError> {
         Ok(Behaviour {
             protocol,
             // debug_info: debug_info::DebugInfoBehaviour::new(user_agent, local_public_key),
-            peer_info: peer_info::PeerInfoBehaviour::new(user_agent, local_public_key),
+            peer_info: peer_info::PeerInfoBehaviour::new(
+                user_agent,
+                local_public_key,
+                keepalive_interval,
+            ),
             discovery: disco_config.finish(),
             request_responses: request_responses::RequestResponsesBehaviour::new(
                 request_response_protocols.into_iter(),
@@ -7,10 +7,11 @@ use libp2p::{
     core::Multiaddr,
     identity::{ed25519, Keypair},
     multiaddr::Protocol,
-    wasm_ext,
+    wasm_ext, PeerId,
 };
 use prometheus::Registry;
 use std::borrow::Cow;
+use std::collections::HashSet;
 use std::fmt;
 use std::{
     error::Error,
@@ -19,6 +20,7 @@ use std::{
     iter,
     net::Ipv4Addr,
     path::{Path, PathBuf},
+    time::Duration,
 };
 use zeroize::Zeroize;
 
@@ -111,6 +113,12 @@ pub struct NetworkConfiguration {
     /// Require iterative Kademlia DHT queries to use disjoint paths for increased resiliency in the
     /// presence of potentially adversarial nodes.
     pub kademlia_disjoint_query_paths: bool,
+    /// Interval of the pings to connected peers. If set, the pings also keep idle connections
+    /// alive, otherwise idle connections may be closed by NAT.
+    pub keepalive_interval: Option<Duration>,
+    /// If not empty, only these peers are allowed. They are reserved in every peer set, which is
+    /// in reserved-only mode, and the connections of other peers are closed once established.
+    pub allowed_peers: HashSet<PeerId>,
 }
 
 /// Configuration for the transport layer.
@@ -163,6 +171,8 @@ impl Default for NetworkConfiguration {
             request_response_protocols: vec![],
             allow_non_globals_in_dht: false,
             kademlia_disjoint_query_paths: false,
+            keepalive_interval: None,
+            allowed_peers: HashSet::new(),
         }
     }
 }
@@ -194,6 +204,8 @@ impl NetworkConfiguration {
             request_response_protocols: vec![],
             allow_non_globals_in_dht: false,
             kademlia_disjoint_query_paths: false,
+            keepalive_interval: None,
+            allowed_peers: HashSet::new(),
         }
     }
 
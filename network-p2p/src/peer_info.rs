@@ -79,7 +79,12 @@ impl NodeInfo {
 
 impl PeerInfoBehaviour {
     /// Builds a new `PeerInfoBehaviour`.
-    pub fn new(user_agent: String, local_public_key: PublicKey) -> Self {
+    /// If `keepalive_interval` is set, ping with the interval and keep the idle connections alive.
+    pub fn new(
+        user_agent: String,
+        local_public_key: PublicKey,
+        keepalive_interval: Option<Duration>,
+    ) -> Self {
         let identify = {
             let proto_version = "/starcoin/1.0".to_string();
             let identify_config =
@@ -87,8 +92,15 @@ impl PeerInfoBehaviour {
             Identify::new(identify_config)
         };
 
+        let ping_config = match keepalive_interval {
+            Some(interval) => PingConfig::new()
+                .with_interval(interval)
+                .with_keep_alive(true),
+            None => PingConfig::new(),
+        };
+
         PeerInfoBehaviour {
-            ping: Ping::new(PingConfig::new()),
+            ping: Ping::new(ping_config),
             identify,
             nodes_info: FnvHashMap::default(),
             garbage_collect: Box::pin(interval(GARBAGE_COLLECT_INTERVAL)),
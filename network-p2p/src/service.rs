@@ -205,14 +205,16 @@ impl NetworkWorker {
             .map(|n| n.peer_id)
             .collect();
 
+        let allowed_peers = params.network_config.allowed_peers.clone();
         for _ in 0..notif_protocols.len() {
             sets_conf.push(sc_peerset::SetConfig {
                 in_peers: params.network_config.in_peers,
                 out_peers: params.network_config.out_peers,
                 bootnodes: bootnodes.clone(),
-                reserved_nodes: s.iter().cloned().collect(),
+                reserved_nodes: s.iter().chain(allowed_peers.iter()).cloned().collect(),
                 reserved_only: params.network_config.non_reserved_mode
-                    == config::NonReservedPeerMode::Deny,
+                    == config::NonReservedPeerMode::Deny
+                    || !allowed_peers.is_empty(),
             });
         }
         let peerset_config = sc_peerset::PeersetConfig { sets: sets_conf };
@@ -269,6 +271,7 @@ impl NetworkWorker {
                 local_public,
                 discovery_config,
                 params.network_config.request_response_protocols,
+                params.network_config.keepalive_interval,
             ) {
                 Ok(behaviour) => behaviour,
                 Err(crate::request_responses::RegisterError::DuplicateProtocol(proto)) => {
@@ -341,6 +344,7 @@ impl NetworkWorker {
             metrics,
             boot_node_ids,
             peers_notifications_sinks,
+            allowed_peers,
         })
     }
 
@@ -841,6 +845,16 @@ impl NetworkService {
         self.num_connected.load(Ordering::Relaxed)
     }
 
+    /// Returns the total number of bytes received so far.
+    pub fn total_bytes_inbound(&self) -> u64 {
+        self.bandwidth.total_inbound()
+    }
+
+    /// Returns the total number of bytes sent so far.
+    pub fn total_bytes_outbound(&self) -> u64 {
+        self.bandwidth.total_outbound()
+    }
+
     pub fn peer_id(&self) -> &PeerId {
         &self.local_peer_id
     }
@@ -991,6 +1005,8 @@ pub struct NetworkWorker {
     /// For each peer, an object that allows sending notifications to
     /// that peer. Shared with the [`NetworkService`].
     peers_notifications_sinks: Arc<Mutex<HashMap<(PeerId, Cow<'static, str>), NotificationsSink>>>,
+    /// If not empty, the connections of peers not in it are closed once established.
+    allowed_peers: HashSet<PeerId>,
 }
 
 impl Future for NetworkWorker {
@@ -1278,6 +1294,10 @@ impl Future for NetworkWorker {
                     peer_id, endpoint, ..
                 }) => {
                     trace!(target: "sub-libp2p", "Libp2p => Connected({:?})", peer_id);
+                    if !this.allowed_peers.is_empty() && !this.allowed_peers.contains(&peer_id) {
+                        info!(target: "sub-libp2p", "Ban peer {:?} which is not in the allowed peers", peer_id);
+                        Swarm::ban_peer_id(&mut this.network_service, peer_id);
+                    }
                     if let Some(metrics) = this.metrics.as_ref() {
                         match endpoint {
                             ConnectedPoint::Dialer { .. } => metrics
@@ -1426,6 +1446,11 @@ impl Future for NetworkWorker {
             };
         }
 
+        let num_connected_peers = Swarm::network_info(&this.network_service).num_peers();
+        this.service
+            .num_connected
+            .store(num_connected_peers, Ordering::Relaxed);
+
         if let Some(metrics) = this.metrics.as_ref() {
             for (proto, buckets) in this
                 .network_service
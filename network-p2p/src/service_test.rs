@@ -603,3 +603,73 @@ fn test_support_protocol() {
         panic!("Unexpected event type: {:?}", open_event2)
     }
 }
+
+/// Starts two nodes without any notification protocol, so the connection between them stays
+/// idle, and samples the number of peers the second node is connected to every second.
+fn sample_idle_connection(keepalive_interval: Option<Duration>, secs: u64) -> Vec<usize> {
+    let protocol = ProtocolId::from("starcoin");
+    let chain = ChainInfo::random();
+    let mut config1 = generate_config(vec![], vec![], vec![]);
+    config1.keepalive_interval = keepalive_interval;
+    let worker1 = NetworkWorker::new(Params::new(
+        config1.clone(),
+        protocol.clone(),
+        chain.clone(),
+        None,
+    ))
+    .unwrap();
+    let service1 = worker1.service().clone();
+    task::spawn(worker1);
+
+    let seed = config::MultiaddrWithPeerId {
+        multiaddr: config1.listen_addresses[0].clone(),
+        peer_id: service1.local_peer_id(),
+    };
+    let mut config2 = generate_config(vec![seed], vec![], vec![]);
+    config2.keepalive_interval = keepalive_interval;
+    let worker2 = NetworkWorker::new(Params::new(config2, protocol, chain, None)).unwrap();
+    let service2 = worker2.service().clone();
+    task::spawn(worker2);
+
+    (0..secs)
+        .map(|_| {
+            thread::sleep(Duration::from_secs(1));
+            service2.num_connected()
+        })
+        .collect()
+}
+
+// The Kademlia random walks dial the boot node at increasing intervals and keep the connection
+// busy for 10s after each query, so sample long enough to cover the gap after the walk at 15s.
+// The tests below are slow and depend on the walk timing, run them with `--ignored`.
+const IDLE_CONNECTION_SAMPLE_SECS: u64 = 40;
+
+#[ignore]
+#[stest::test]
+fn test_idle_connection_closed_without_keepalive() {
+    let samples = sample_idle_connection(None, IDLE_CONNECTION_SAMPLE_SECS);
+    let connected_at = samples
+        .iter()
+        .position(|num| *num == 1)
+        .expect("the nodes should be connected");
+    assert!(
+        samples[connected_at..].contains(&0),
+        "idle connection should be closed, samples: {:?}",
+        samples
+    );
+}
+
+#[ignore]
+#[stest::test]
+fn test_keepalive() {
+    let samples = sample_idle_connection(Some(Duration::from_secs(1)), IDLE_CONNECTION_SAMPLE_SECS);
+    let connected_at = samples
+        .iter()
+        .position(|num| *num == 1)
+        .expect("the nodes should be connected");
+    assert!(
+        samples[connected_at..].iter().all(|num| *num == 1),
+        "idle connection should be kept alive, samples: {:?}",
+        samples
+    );
+}
@@ -320,6 +320,35 @@ pub struct ReportReputation {
     pub change: ReputationChange,
 }
 
+/// Pause the network service, messages are buffered until resume and peer connections are kept.
+#[derive(Clone, Debug)]
+pub struct PauseNetwork;
+
+/// Resume the paused network service, and handle the buffered messages.
+#[derive(Clone, Debug)]
+pub struct ResumeNetwork;
+
+/// A snapshot of the network health, for monitoring exporters.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct NetworkHealth {
+    pub peer_count: usize,
+    /// Whether any of the seed nodes is connected.
+    pub seed_connected: bool,
+    pub total_bytes_inbound: u64,
+    pub total_bytes_outbound: u64,
+    /// Outbound messages waiting to be sent, they only queue up while the network is paused.
+    pub outbound_queue_depth: usize,
+    /// Messages dropped because the buffer was full while the network was paused, since start.
+    pub paused_messages_dropped: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetNetworkHealth;
+
+impl ServiceRequest for GetNetworkHealth {
+    type Response = NetworkHealth;
+}
+
 #[derive(Clone, Debug)]
 pub struct PeerReputations {
     pub threshold: i32,
@@ -12,6 +12,6 @@ pub mod worker;
 pub use network_api::messages::*;
 
 pub use helper::{get_unix_ts, get_unix_ts_as_millis};
-pub use service::NetworkActorService;
+pub use service::{NetworkActorService, MAX_PAUSED_MESSAGES};
 pub use service_ref::NetworkServiceRef;
 pub use worker::build_network_worker;
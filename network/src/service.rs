@@ -8,11 +8,12 @@ use anyhow::{format_err, Result};
 use bytes::Bytes;
 use futures::future::{abortable, AbortHandle};
 use futures::FutureExt;
-use log::{debug, error, info, trace};
+use log::{debug, error, info, trace, warn};
 use lru::LruCache;
 use network_api::messages::{
-    AnnouncementType, GetPeerById, GetPeerSet, GetSelfPeer, NotificationMessage, PeerEvent,
-    PeerMessage, PeerReputations, ReportReputation, TransactionsMessage,
+    AnnouncementType, GetNetworkHealth, GetPeerById, GetPeerSet, GetSelfPeer, NetworkHealth,
+    NotificationMessage, PauseNetwork, PeerEvent, PeerMessage, PeerReputations, ReportReputation,
+    ResumeNetwork, TransactionsMessage,
 };
 use network_api::peer_score::{BlockBroadcastEntry, HandleState, LinearScore, Score};
 use network_api::{BroadcastProtocolFilter, NetworkActor, PeerMessageHandler};
@@ -30,15 +31,34 @@ use starcoin_types::startup_info::{ChainInfo, ChainStatus};
 use starcoin_types::sync_status::SyncStatus;
 use starcoin_types::system_events::SyncStatusChangeEvent;
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::ops::RangeInclusive;
 use std::sync::Arc;
 
+/// Max messages buffered while the network is paused, later messages are dropped.
+pub const MAX_PAUSED_MESSAGES: usize = 1024;
+
+enum PausedMessage {
+    Broadcast(NotificationMessage),
+    Peer(PeerMessage),
+    Received {
+        remote: PeerId,
+        protocol: Cow<'static, str>,
+        message: Bytes,
+    },
+}
+
 pub struct NetworkActorService {
     worker: Option<NetworkWorker>,
     inner: Inner,
 
     network_worker_handle: Option<AbortHandle>,
+    paused: bool,
+    paused_messages: VecDeque<PausedMessage>,
+    /// Messages dropped during the current pause.
+    paused_dropped: u64,
+    /// Messages dropped during all pauses.
+    total_paused_dropped: u64,
 }
 
 impl NetworkActor for NetworkActorService {}
@@ -66,12 +86,82 @@ impl NetworkActorService {
             worker: Some(worker),
             inner,
             network_worker_handle: None,
+            paused: false,
+            paused_messages: VecDeque::new(),
+            paused_dropped: 0,
+            total_paused_dropped: 0,
         })
     }
 
     pub fn network_service(&self) -> Arc<network_p2p::NetworkService> {
         self.inner.network_service.clone()
     }
+
+    /// Collect the network health, it only reads counters and the local peer table so it does not
+    /// hold the actor for long.
+    pub fn health(&self) -> NetworkHealth {
+        let seed_connected = self
+            .inner
+            .config
+            .network
+            .seeds()
+            .into_iter()
+            .any(|seed| self.inner.peers.contains_key(&PeerId::new(seed.peer_id)));
+        NetworkHealth {
+            peer_count: self.inner.peers.len(),
+            seed_connected,
+            total_bytes_inbound: self.inner.network_service.total_bytes_inbound(),
+            total_bytes_outbound: self.inner.network_service.total_bytes_outbound(),
+            outbound_queue_depth: self
+                .paused_messages
+                .iter()
+                .filter(|msg| !matches!(msg, PausedMessage::Received { .. }))
+                .count(),
+            paused_messages_dropped: self.total_paused_dropped,
+        }
+    }
+
+    /// Buffer the message if the network is paused, return the message back if not paused.
+    fn try_buffer(&mut self, msg: PausedMessage) -> Option<PausedMessage> {
+        if !self.paused {
+            return Some(msg);
+        }
+        if self.paused_messages.len() < MAX_PAUSED_MESSAGES {
+            self.paused_messages.push_back(msg);
+        } else {
+            if self.paused_dropped == 0 {
+                warn!(
+                    "Network is paused and the buffer of {} messages is full, drop later messages.",
+                    MAX_PAUSED_MESSAGES
+                );
+            }
+            self.paused_dropped = self.paused_dropped.saturating_add(1);
+            self.total_paused_dropped = self.total_paused_dropped.saturating_add(1);
+        }
+        None
+    }
+
+    fn handle_message(&mut self, msg: PausedMessage) {
+        match msg {
+            PausedMessage::Broadcast(notification) => self.inner.broadcast(notification),
+            PausedMessage::Peer(msg) => self.inner.send_peer_message(msg.peer_id, msg.notification),
+            PausedMessage::Received {
+                remote,
+                protocol,
+                message,
+            } => {
+                if let Err(e) =
+                    self.inner
+                        .handle_network_message(remote.clone(), protocol.clone(), message)
+                {
+                    error!(
+                        "Handle network message fail, remote:{}, protocol:{}, error: {:?}",
+                        remote, protocol, e
+                    )
+                }
+            }
+        }
+    }
 }
 
 impl ActorService for NetworkActorService {
@@ -144,15 +234,13 @@ impl EventHandler<Self, Event> for NetworkActorService {
             }
             Event::NotificationsReceived { remote, messages } => {
                 for (protocol, message) in messages {
-                    if let Err(e) = self.inner.handle_network_message(
-                        remote.clone().into(),
-                        protocol.clone(),
+                    let msg = PausedMessage::Received {
+                        remote: remote.clone().into(),
+                        protocol,
                         message,
-                    ) {
-                        error!(
-                            "Handle network message fail, remote:{}, protocol:{}, error: {:?}",
-                            remote, protocol, e
-                        )
+                    };
+                    if let Some(msg) = self.try_buffer(msg) {
+                        self.handle_message(msg);
                     }
                 }
             }
@@ -178,13 +266,48 @@ impl EventHandler<Self, NotificationMessage> for NetworkActorService {
         msg: NotificationMessage,
         _ctx: &mut ServiceContext<NetworkActorService>,
     ) {
-        self.inner.broadcast(msg);
+        if let Some(msg) = self.try_buffer(PausedMessage::Broadcast(msg)) {
+            self.handle_message(msg);
+        }
     }
 }
 
 impl EventHandler<Self, PeerMessage> for NetworkActorService {
     fn handle_event(&mut self, msg: PeerMessage, _ctx: &mut ServiceContext<NetworkActorService>) {
-        self.inner.send_peer_message(msg.peer_id, msg.notification);
+        if let Some(msg) = self.try_buffer(PausedMessage::Peer(msg)) {
+            self.handle_message(msg);
+        }
+    }
+}
+
+impl EventHandler<Self, PauseNetwork> for NetworkActorService {
+    fn handle_event(&mut self, _msg: PauseNetwork, _ctx: &mut ServiceContext<NetworkActorService>) {
+        info!("Network paused.");
+        self.paused = true;
+    }
+}
+
+impl EventHandler<Self, ResumeNetwork> for NetworkActorService {
+    fn handle_event(
+        &mut self,
+        _msg: ResumeNetwork,
+        _ctx: &mut ServiceContext<NetworkActorService>,
+    ) {
+        info!(
+            "Network resumed, handle {} paused messages.",
+            self.paused_messages.len()
+        );
+        if self.paused_dropped > 0 {
+            warn!(
+                "{} messages were dropped while the network was paused.",
+                self.paused_dropped
+            );
+            self.paused_dropped = 0;
+        }
+        self.paused = false;
+        while let Some(msg) = self.paused_messages.pop_front() {
+            self.handle_message(msg);
+        }
     }
 }
 
@@ -200,10 +323,12 @@ impl EventHandler<Self, PropagateTransactions> for NetworkActorService {
             return;
         }
         debug!("prepare to propagate txns, len: {}", txns.len());
-        self.inner
-            .broadcast(NotificationMessage::Transactions(TransactionsMessage::new(
-                txns,
-            )));
+        let msg = PausedMessage::Broadcast(NotificationMessage::Transactions(
+            TransactionsMessage::new(txns),
+        ));
+        if let Some(msg) = self.try_buffer(msg) {
+            self.handle_message(msg);
+        }
     }
 }
 
@@ -221,6 +346,16 @@ impl ServiceHandler<Self, GetPeerSet> for NetworkActorService {
     }
 }
 
+impl ServiceHandler<Self, GetNetworkHealth> for NetworkActorService {
+    fn handle(
+        &mut self,
+        _msg: GetNetworkHealth,
+        _ctx: &mut ServiceContext<NetworkActorService>,
+    ) -> <GetNetworkHealth as ServiceRequest>::Response {
+        self.health()
+    }
+}
+
 impl ServiceHandler<Self, PeerReputations> for NetworkActorService {
     fn handle(
         &mut self,
@@ -9,7 +9,9 @@ use futures::channel::oneshot::Receiver;
 use futures::future::BoxFuture;
 use futures::FutureExt;
 use log::warn;
-use network_api::messages::NotificationMessage;
+use network_api::messages::{
+    GetNetworkHealth, NetworkHealth, NotificationMessage, PauseNetwork, ResumeNetwork,
+};
 use network_api::{NetworkService, PeerProvider, ReputationChange, SupportedRpcProtocol};
 use network_p2p_types::network_state::NetworkState;
 use network_p2p_types::{IfDisconnected, Multiaddr, RequestFailure};
@@ -151,4 +153,22 @@ impl NetworkServiceRef {
     pub async fn is_connected(&self, peer_id: PeerId) -> bool {
         self.network_service.is_connected(peer_id.into()).await
     }
+
+    pub async fn health(&self) -> Result<NetworkHealth> {
+        self.service_ref.send(GetNetworkHealth).await
+    }
+
+    /// Stop handling inbound and outbound messages, but keep the peer connections alive.
+    pub fn pause(&self) -> Result<()> {
+        self.service_ref
+            .notify(PauseNetwork)
+            .map_err(|e| format_err!("{:?}", e))
+    }
+
+    /// Handle the messages buffered during pause, and continue handling new messages.
+    pub fn resume(&self) -> Result<()> {
+        self.service_ref
+            .notify(ResumeNetwork)
+            .map_err(|e| format_err!("{:?}", e))
+    }
 }
@@ -84,16 +84,19 @@ pub fn build_network_worker(
             .map(|config| config.name.clone())
             .collect(),
     );
+    let secret = identity::ed25519::SecretKey::from_bytes(
+        &mut network_config.network_keypair().0.to_bytes(),
+    )
+    .map_err(|e| {
+        format_err!(
+            "Decode network node key fail, please check the network key file or config: {:?}",
+            e
+        )
+    })?;
     let config = NetworkConfiguration {
         listen_addresses: vec![network_config.listen()],
         boot_nodes,
-        node_key: {
-            let secret = identity::ed25519::SecretKey::from_bytes(
-                &mut network_config.network_keypair().0.to_bytes(),
-            )
-            .expect("decode network node key should success.");
-            NodeKeyConfig::Ed25519(Secret::Input(secret))
-        },
+        node_key: NodeKeyConfig::Ed25519(Secret::Input(secret)),
         in_peers: network_config.max_incoming_peers(),
         out_peers: network_config.max_outgoing_peers(),
         notifications_protocols: protocols,
@@ -102,6 +105,14 @@ pub fn build_network_worker(
         node_name,
         client_version: starcoin_config::APP_NAME_WITH_VERSION.clone(),
         allow_non_globals_in_dht,
+        keepalive_interval: network_config.keepalive_interval(),
+        allowed_peers: network_config
+            .allowed_peers
+            .iter()
+            .flatten()
+            .cloned()
+            .map(Into::into)
+            .collect(),
         ..NetworkConfiguration::default()
     };
     // protocol id is chain/{chain_id}, `RegisteredProtocol` will append `/starcoin` prefix
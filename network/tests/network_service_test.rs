@@ -8,14 +8,15 @@ use network_api::messages::{
     Announcement, AnnouncementType, CompactBlockMessage, NotificationMessage, PeerMessage,
     TransactionsMessage, ANNOUNCEMENT_PROTOCOL_NAME, TXN_PROTOCOL_NAME,
 };
-use network_api::{Multiaddr, NetworkService};
+use network_api::{Multiaddr, NetworkService, PeerProvider};
 use network_p2p_types::MultiaddrWithPeerId;
 use starcoin_config::{BuiltinNetworkID, NetworkConfig, NodeConfig};
 use starcoin_crypto::hash::HashValue;
 use starcoin_logger::prelude::*;
-use starcoin_network::build_network_worker;
+use starcoin_network::{build_network_worker, MAX_PAUSED_MESSAGES};
 use starcoin_types::block::{AccumulatorInfo, Block, BlockBody, BlockHeader, BlockInfo};
 use starcoin_types::cmpact_block::CompactBlock;
+use starcoin_types::peer_info::PeerId;
 use starcoin_types::startup_info::{ChainInfo, ChainStatus};
 use starcoin_types::transaction::SignedUserTransaction;
 use starcoin_types::U256;
@@ -361,3 +362,131 @@ async fn test_filter_protocol() {
         msg_3.notification.protocol_name()
     );
 }
+
+#[stest::test]
+async fn test_allowed_peers() {
+    let mut node_config_1 = NodeConfig::random_for_test();
+    node_config_1.network.allowed_peers = Some(vec![PeerId::random()]);
+    let node_config_1 = Arc::new(node_config_1);
+    let service1 = build_network_with_config(node_config_1.clone(), None)
+        .await
+        .unwrap();
+
+    let nodes = vec![MultiaddrWithPeerId::new(
+        node_config_1.network.listen(),
+        service1.peer_id().into(),
+    )];
+    let mut node_config_2 = NodeConfig::random_for_test();
+    node_config_2.network.seeds = nodes.into();
+    let service2 = build_network_with_config(Arc::new(node_config_2), None)
+        .await
+        .unwrap();
+    Delay::new(Duration::from_secs(2)).await;
+
+    // service2 is not in the allowed peers of service1, so the connection is closed on both sides.
+    let peers = service1.service_ref.peer_set().await.unwrap();
+    assert!(peers
+        .iter()
+        .all(|peer| peer.peer_id() != service2.peer_id()));
+    let peers = service2.service_ref.peer_set().await.unwrap();
+    assert!(peers
+        .iter()
+        .all(|peer| peer.peer_id() != service1.peer_id()));
+    assert!(!service2.service_ref.is_connected(service1.peer_id()).await);
+}
+
+#[stest::test]
+async fn test_pause_resume() {
+    let node_config_1 = Arc::new(NodeConfig::random_for_test());
+    let service1 = build_network_with_config(node_config_1.clone(), None)
+        .await
+        .unwrap();
+
+    let nodes = vec![MultiaddrWithPeerId::new(
+        node_config_1.network.listen(),
+        service1.peer_id().into(),
+    )];
+    let mut node_config_2 = NodeConfig::random_for_test();
+    node_config_2.network.seeds = nodes.into();
+    let service2 = build_network_with_config(Arc::new(node_config_2), None)
+        .await
+        .unwrap();
+    Delay::new(Duration::from_secs(2)).await;
+    assert!(service1.service_ref.is_connected(service2.peer_id()).await);
+
+    let mut receiver2 = service2.message_handler.channel();
+
+    service1.service_ref.pause().unwrap();
+    let ids = vec![HashValue::random()];
+    let announcement =
+        NotificationMessage::Announcement(Announcement::new(AnnouncementType::Txn, ids));
+    let peer_message = PeerMessage::new(service2.peer_id(), announcement.clone());
+    service1.service_ref.send_peer_message(peer_message);
+
+    let msg_2 = async_std::future::timeout(Duration::from_secs(1), receiver2.next()).await;
+    assert!(msg_2.is_err());
+    // the connection keep alive during pause.
+    assert!(service1.service_ref.is_connected(service2.peer_id()).await);
+
+    service1.service_ref.resume().unwrap();
+    let msg_2 = receiver2.next().await.unwrap();
+    assert_eq!(announcement, msg_2.notification);
+}
+
+#[stest::test]
+async fn test_network_health() {
+    let node_config_1 = Arc::new(NodeConfig::random_for_test());
+    let service1 = build_network_with_config(node_config_1.clone(), None)
+        .await
+        .unwrap();
+
+    let nodes = vec![MultiaddrWithPeerId::new(
+        node_config_1.network.listen(),
+        service1.peer_id().into(),
+    )];
+    let mut node_config_2 = NodeConfig::random_for_test();
+    node_config_2.network.seeds = nodes.clone().into();
+    let service2 = build_network_with_config(Arc::new(node_config_2), None)
+        .await
+        .unwrap();
+    let mut node_config_3 = NodeConfig::random_for_test();
+    node_config_3.network.seeds = nodes.into();
+    let service3 = build_network_with_config(Arc::new(node_config_3), None)
+        .await
+        .unwrap();
+    Delay::new(Duration::from_secs(2)).await;
+    assert!(service1.service_ref.is_connected(service2.peer_id()).await);
+    assert!(service1.service_ref.is_connected(service3.peer_id()).await);
+
+    let health1 = service1.service_ref.health().await.unwrap();
+    assert_eq!(health1.peer_count, 2);
+    assert!(!health1.seed_connected);
+    assert!(health1.total_bytes_inbound > 0);
+    assert!(health1.total_bytes_outbound > 0);
+    assert_eq!(health1.outbound_queue_depth, 0);
+    assert_eq!(health1.paused_messages_dropped, 0);
+
+    let health2 = service2.service_ref.health().await.unwrap();
+    assert!(health2.seed_connected);
+
+    service1.service_ref.pause().unwrap();
+    let ids = vec![HashValue::random()];
+    let announcement =
+        NotificationMessage::Announcement(Announcement::new(AnnouncementType::Txn, ids));
+    service1.service_ref.broadcast(announcement);
+    let health1 = service1.service_ref.health().await.unwrap();
+    assert_eq!(health1.outbound_queue_depth, 1);
+    for _ in 0..MAX_PAUSED_MESSAGES {
+        let ids = vec![HashValue::random()];
+        service1
+            .service_ref
+            .broadcast(NotificationMessage::Announcement(Announcement::new(
+                AnnouncementType::Txn,
+                ids,
+            )));
+    }
+    let health1 = service1.service_ref.health().await.unwrap();
+    assert_eq!(health1.outbound_queue_depth, MAX_PAUSED_MESSAGES);
+    assert_eq!(health1.paused_messages_dropped, 1);
+    service1.service_ref.resume().unwrap();
+}
@@ -229,6 +229,11 @@ impl NodeService {
             logger_handle.enable_stderr();
         }
 
+        if let Some(enable) = config.vm_runtime.enable_debug_natives {
+            info!("Set debug natives enabled: {}", enable);
+            starcoin_vm_runtime::natives::set_debug_natives_enabled(enable);
+        }
+
         // start metric server
         if let Some(metrics_address) = config.metrics.metrics_address() {
             starcoin_metrics::metric_server::start_server(metrics_address);
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2
 
 pub use self::gen_client::Client as NodeClient;
-use crate::types::PeerInfoView;
+use crate::types::{NativesView, PeerInfoView};
 use crate::FutureResult;
 use jsonrpc_core::Result;
 use jsonrpc_derive::rpc;
@@ -55,6 +55,10 @@ pub trait NodeApi {
 
     #[rpc(name = "node.metrics")]
     fn metrics(&self) -> Result<HashMap<String, String>>;
+
+    /// Get the native functions registered by the node's VM, and the options the VM is built with.
+    #[rpc(name = "node.natives")]
+    fn natives(&self) -> Result<NativesView>;
 }
 #[test]
 fn test() {
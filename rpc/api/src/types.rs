@@ -1130,6 +1130,25 @@ pub struct PeerInfoView {
     pub rpc_protocols: String,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct NativeFunctionView {
+    pub address: AccountAddress,
+    pub module: String,
+    pub function: String,
+    /// The native is only registered in `testing` feature builds.
+    pub testing_only: bool,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct NativesView {
+    /// The node is built with the `testing` feature of the VM.
+    pub testing_build: bool,
+    /// `Debug::print` and `Debug::print_stack_trace` print to the node stdout, instead of no-op.
+    pub debug_natives_enabled: bool,
+    /// The natives registered by the node's VM.
+    pub natives: Vec<NativeFunctionView>,
+}
+
 impl From<PeerInfo> for PeerInfoView {
     fn from(info: PeerInfo) -> Self {
         Self {
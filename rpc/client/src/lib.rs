@@ -31,7 +31,7 @@ use starcoin_rpc_api::types::{
     AccountStateSetView, AnnotatedMoveStructView, BlockHeaderView, BlockView, ChainId,
     ChainInfoView, CodeView, ContractCall, DecodedMoveValue, DryRunOutputView,
     DryRunTransactionRequest, FactoryAction, FunctionIdView, ListCodeView, ListResourceView,
-    MintedBlockView, ModuleIdView, PeerInfoView, ResourceView, SignedMessageView,
+    MintedBlockView, ModuleIdView, NativesView, PeerInfoView, ResourceView, SignedMessageView,
     SignedUserTransactionView, StateWithProofView, StrView, StructTagView,
     TransactionEventResponse, TransactionInfoView, TransactionRequest, TransactionView,
 };
@@ -218,6 +218,11 @@ impl RpcClient {
             .map_err(map_err)
     }
 
+    pub fn node_natives(&self) -> anyhow::Result<NativesView> {
+        self.call_rpc_blocking(|inner| inner.node_client.natives())
+            .map_err(map_err)
+    }
+
     pub fn node_peers(&self) -> anyhow::Result<Vec<PeerInfoView>> {
         self.call_rpc_blocking(|inner| inner.node_client.peers())
             .map_err(map_err)
@@ -9,8 +9,11 @@ use network_api::PeerProvider;
 use starcoin_config::NodeConfig;
 use starcoin_network::NetworkServiceRef;
 use starcoin_rpc_api::node::{NodeApi, NodeInfo};
-use starcoin_rpc_api::types::PeerInfoView;
+use starcoin_rpc_api::types::{NativeFunctionView, NativesView, PeerInfoView};
 use starcoin_rpc_api::FutureResult;
+use starcoin_vm_runtime::natives::{
+    is_testing_build, native_descriptors_with_options, NativeFunctionOptions,
+};
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -66,4 +69,23 @@ impl NodeApi for NodeRpcImpl {
     fn metrics(&self) -> Result<HashMap<String, String>> {
         Ok(starcoin_metrics::get_all_metrics())
     }
+
+    fn natives(&self) -> Result<NativesView> {
+        // the same options as the VMs created by the executor.
+        let options = NativeFunctionOptions::default();
+        let natives = native_descriptors_with_options(options)
+            .into_iter()
+            .map(|descriptor| NativeFunctionView {
+                address: descriptor.address,
+                module: descriptor.module_name.to_string(),
+                function: descriptor.func_name.to_string(),
+                testing_only: descriptor.testing_only,
+            })
+            .collect();
+        Ok(NativesView {
+            testing_build: is_testing_build(),
+            debug_natives_enabled: options.enable_debug,
+            natives,
+        })
+    }
 }
@@ -15,6 +15,7 @@ use starcoin_types::{
     transaction::{SignedUserTransaction, Transaction, TransactionOutput},
     write_set::WriteSet,
 };
+use starcoin_vm_runtime::natives::NativeFunctionOptions;
 use starcoin_vm_runtime::starcoin_vm::StarcoinVM;
 use starcoin_vm_types::account_config::{genesis_address, STC_TOKEN_CODE_STR};
 use starcoin_vm_types::move_resource::MoveResource;
@@ -181,7 +182,10 @@ impl FakeExecutor {
         &self,
         txn_block: Vec<Transaction>,
     ) -> Result<Vec<(VMStatus, TransactionOutput)>> {
-        let mut vm = StarcoinVM::new();
+        let mut vm = StarcoinVM::new_with_native_options(NativeFunctionOptions {
+            enable_debug: true,
+            ..Default::default()
+        });
         vm.execute_block_transactions(&self.data_store, txn_block, None)
     }
 
@@ -202,7 +206,10 @@ impl FakeExecutor {
 
     /// Verifies the given transaction by running it through the VM verifier.
     pub fn verify_transaction(&self, txn: SignedUserTransaction) -> Option<VMStatus> {
-        let mut vm = StarcoinVM::new();
+        let mut vm = StarcoinVM::new_with_native_options(NativeFunctionOptions {
+            enable_debug: true,
+            ..Default::default()
+        });
         vm.verify_transaction(&self.data_store, txn)
     }
 
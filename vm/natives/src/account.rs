@@ -1,6 +1,7 @@
 // Copyright (c) The Diem Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::gas_schedule::{starcoin_native_gas, StarcoinNativeCostIndex};
 use move_binary_format::errors::PartialVMResult;
 use move_core_types::account_address::AccountAddress;
 use move_vm_runtime::native_functions::NativeContext;
@@ -12,6 +13,7 @@ use move_vm_types::{
     values::Value,
 };
 use smallvec::smallvec;
+use starcoin_vm_types::account_address::create_resource_address;
 use std::collections::VecDeque;
 
 pub fn native_create_signer(
@@ -40,3 +42,26 @@ pub fn native_destroy_signer(
     let cost = native_gas(context.cost_table(), NativeCostIndex::DESTROY_SIGNER, 0);
     Ok(NativeResult::ok(cost, smallvec![]))
 }
+
+/// Rust implementation of Move's
+/// `native public fun create_resource_address(source: address, seed: vector<u8>): address;`
+/// The address is derived by `starcoin_vm_types::account_address::create_resource_address`.
+pub fn native_create_resource_address(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let seed = pop_arg!(arguments, Vec<u8>);
+    let source = pop_arg!(arguments, AccountAddress);
+    // the source address is the fixed part of the hashed bytes.
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::CREATE_RESOURCE_ADDRESS,
+        AccountAddress::LENGTH + seed.len(),
+    );
+    let address = create_resource_address(&source, &seed);
+    Ok(NativeResult::ok(cost, smallvec![Value::address(address)]))
+}
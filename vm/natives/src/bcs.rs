@@ -1,6 +1,8 @@
+use crate::gas_schedule::{starcoin_native_gas, StarcoinNativeCostIndex};
+use crate::sub_status::{NFE_BCS_FROM_BYTES_FAILURE, NFE_BCS_TO_ADDRESS_FAILURE};
 use move_binary_format::errors::PartialVMResult;
 use move_core_types::account_address::AccountAddress;
-use move_core_types::vm_status::sub_status::NFE_BCS_TO_ADDRESS_FAILURE;
+use move_core_types::value::MoveTypeLayout;
 use move_vm_runtime::native_functions::NativeContext;
 use move_vm_types::gas_schedule::NativeCostIndex;
 use move_vm_types::loaded_data::runtime_types::Type;
@@ -11,7 +13,9 @@ use smallvec::smallvec;
 use std::collections::VecDeque;
 use std::convert::TryFrom;
 
-/// Rust implementation of Move's `public fun from_public_key_vec(pub_key_vec: vector<u8>): address;`
+/// Rust implementation of Move's `native public fun to_address(key_bytes: vector<u8>): address;`
+/// Abort with `NFE_BCS_TO_ADDRESS_FAILURE` (0x1C6) if the length of `key_bytes` is not
+/// `AccountAddress::LENGTH`, instead of raising a VM error.
 pub fn native_to_address(
     context: &mut NativeContext,
     mut _ty_args: Vec<Type>,
@@ -30,10 +34,100 @@ pub fn native_to_address(
         return Ok(NativeResult::err(cost, NFE_BCS_TO_ADDRESS_FAILURE));
     }
 
-    let address = match AccountAddress::try_from(&key_bytes[..AccountAddress::LENGTH]) {
+    let address = match AccountAddress::try_from(key_bytes.as_slice()) {
         Ok(addr) => addr,
         Err(_) => return Ok(NativeResult::err(cost, NFE_BCS_TO_ADDRESS_FAILURE)),
     };
     let return_values = smallvec![Value::address(address)];
     Ok(NativeResult::ok(cost, return_values))
 }
+
+/// Rust implementation of Move's `native public fun from_bytes<MoveValue: copy + drop>(bytes: vector<u8>): MoveValue;`
+/// The inverse of `to_bytes`, abort with `NFE_BCS_FROM_BYTES_FAILURE` if `bytes` is not a
+/// complete BCS encoding of `MoveValue`.
+/// The Move declaration must keep `copy + drop`, otherwise resources could be forged from bytes.
+/// As a second guard, the native aborts for the types whose layout shows they lack
+/// `copy + drop`, the abilities of a struct itself are not visible to the native.
+pub fn native_from_bytes(
+    context: &mut NativeContext,
+    mut ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 1);
+
+    let ty = ty_args.pop().unwrap();
+    let bytes = pop_arg!(args, Vec<u8>);
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::BCS_FROM_BYTES,
+        bytes.len(),
+    );
+    let layout = match context.type_to_type_layout(&ty)? {
+        Some(layout) => layout,
+        None => return Ok(NativeResult::err(cost, NFE_BCS_FROM_BYTES_FAILURE)),
+    };
+    if !layout_has_copy_and_drop(&layout) {
+        return Ok(NativeResult::err(cost, NFE_BCS_FROM_BYTES_FAILURE));
+    }
+    match from_bytes(&bytes, &layout) {
+        Some(value) => Ok(NativeResult::ok(cost, smallvec![value])),
+        None => Ok(NativeResult::err(cost, NFE_BCS_FROM_BYTES_FAILURE)),
+    }
+}
+
+/// Return false if a `signer`, which has neither `copy` nor `drop`, is in the layout.
+fn layout_has_copy_and_drop(layout: &MoveTypeLayout) -> bool {
+    match layout {
+        MoveTypeLayout::Signer => false,
+        MoveTypeLayout::Vector(element) => layout_has_copy_and_drop(element),
+        MoveTypeLayout::Struct(struct_layout) => {
+            struct_layout.fields().iter().all(layout_has_copy_and_drop)
+        }
+        _ => true,
+    }
+}
+
+fn from_bytes(bytes: &[u8], layout: &MoveTypeLayout) -> Option<Value> {
+    Value::simple_deserialize(bytes, layout)
+}
+
+#[test]
+fn test_from_bytes() {
+    use move_core_types::value::MoveStructLayout;
+    use move_vm_types::values::Struct;
+
+    let layout = MoveTypeLayout::Struct(MoveStructLayout::new(vec![
+        MoveTypeLayout::U64,
+        MoveTypeLayout::Address,
+        MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+    ]));
+    let value = Value::struct_(Struct::pack(vec![
+        Value::u64(42),
+        Value::address(AccountAddress::new([1u8; AccountAddress::LENGTH])),
+        Value::vector_u8(b"starcoin".to_vec()),
+    ]));
+    let bytes = value.simple_serialize(&layout).unwrap();
+
+    let decoded = from_bytes(&bytes, &layout).unwrap();
+    assert!(decoded.equals(&value).unwrap());
+    assert!(from_bytes(&bytes[..bytes.len() - 1], &layout).is_none());
+    assert!(from_bytes(&[bytes.as_slice(), &[0u8]].concat(), &layout).is_none());
+    assert!(from_bytes(&bytes, &MoveTypeLayout::U64).is_none());
+}
+
+#[test]
+fn test_layout_has_copy_and_drop() {
+    use move_core_types::value::MoveStructLayout;
+
+    assert!(layout_has_copy_and_drop(&MoveTypeLayout::Vector(Box::new(
+        MoveTypeLayout::Address
+    ))));
+    assert!(!layout_has_copy_and_drop(&MoveTypeLayout::Signer));
+    assert!(!layout_has_copy_and_drop(&MoveTypeLayout::Struct(
+        MoveStructLayout::new(vec![
+            MoveTypeLayout::U64,
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::Signer)),
+        ])
+    )));
+}
@@ -0,0 +1,152 @@
+use crate::gas_schedule::{starcoin_native_gas, StarcoinNativeCostIndex};
+use crate::sub_status::{NFE_BIGINT_MODEXP_INPUT_TOO_LONG, NFE_BIGINT_MODEXP_ZERO_MODULUS};
+use move_binary_format::errors::PartialVMResult;
+use move_vm_runtime::native_functions::NativeContext;
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+};
+use num_bigint::BigUint;
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+/// The max byte length of each of the base, exponent and modulus.
+pub const MAX_MODEXP_INPUT_LENGTH: usize = 512;
+
+/// Rust implementation of Move's `native public fun modexp(base: vector<u8>, exponent: vector<u8>, modulus: vector<u8>): vector<u8>;`
+/// All the numbers are big-endian unsigned integers. Abort with `NFE_BIGINT_MODEXP_ZERO_MODULUS`
+/// if the modulus is zero, and with `NFE_BIGINT_MODEXP_INPUT_TOO_LONG` if any number is longer
+/// than `MAX_MODEXP_INPUT_LENGTH` bytes.
+pub fn native_modexp(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let modulus = pop_arg!(arguments, Vec<u8>);
+    let exponent = pop_arg!(arguments, Vec<u8>);
+    let base = pop_arg!(arguments, Vec<u8>);
+
+    if base.len() > MAX_MODEXP_INPUT_LENGTH
+        || exponent.len() > MAX_MODEXP_INPUT_LENGTH
+        || modulus.len() > MAX_MODEXP_INPUT_LENGTH
+    {
+        let cost = starcoin_native_gas(
+            context.cost_table(),
+            StarcoinNativeCostIndex::BIGINT_MODEXP,
+            0,
+        );
+        return Ok(NativeResult::err(cost, NFE_BIGINT_MODEXP_INPUT_TOO_LONG));
+    }
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::BIGINT_MODEXP,
+        modexp_complexity(base.len(), exponent.as_slice(), modulus.len()),
+    );
+    match modexp(base.as_slice(), exponent.as_slice(), modulus.as_slice()) {
+        Some(result) => Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(result)])),
+        None => Ok(NativeResult::err(cost, NFE_BIGINT_MODEXP_ZERO_MODULUS)),
+    }
+}
+
+/// The complexity of modexp as EIP-2565: the cost of a multiplication on the words of the larger
+/// of base and modulus, times the count of squarings derived from the exponent.
+fn modexp_complexity(base_len: usize, exponent: &[u8], modulus_len: usize) -> usize {
+    let words = (std::cmp::max(base_len, modulus_len) + 7) / 8;
+    let multiplication_complexity = words * words;
+    // the bit length of the highest 32 bytes, plus 8 bits for every byte below them.
+    let head = BigUint::from_bytes_be(&exponent[..std::cmp::min(32, exponent.len())]);
+    let head_bits = head.bits().saturating_sub(1) as usize;
+    let iteration_count = 8 * exponent.len().saturating_sub(32) + head_bits;
+    multiplication_complexity * std::cmp::max(1, iteration_count)
+}
+
+/// Return None if the modulus is zero.
+fn modexp(base: &[u8], exponent: &[u8], modulus: &[u8]) -> Option<Vec<u8>> {
+    let modulus = BigUint::from_bytes_be(modulus);
+    if modulus == BigUint::from(0u8) {
+        return None;
+    }
+    let base = BigUint::from_bytes_be(base);
+    let exponent = BigUint::from_bytes_be(exponent);
+    Some(base.modpow(&exponent, &modulus).to_bytes_be())
+}
+
+#[test]
+fn test_modexp() {
+    const NAGYDANI_1_BASE: &str = "e09ad9675465c53a109fac66a445c91b292d2bb2c5268addb30cd82f80fcb0033ff97c80a5fc6f39193ae969c6ede6710a6b7ac27078a06d90ef1c72e5c85fb5";
+    const NAGYDANI_1_MODULUS: &str = "fc9e1f6beb81516545975218075ec2af118cd8798df6e08a147c60fd6095ac2bb02c2908cf4dd7c81f11c289e4bce98f3553768f392a80ce22bf5c4f4a248c6b";
+    let cases = vec![
+        ("03", "05", "07", "05"),
+        ("02", "0a", "03e8", "18"),
+        ("05", "", "07", "01"),
+        ("05", "00", "01", "00"),
+        // the examples of EIP-198.
+        (
+            "03",
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e",
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+            "01",
+        ),
+        (
+            "",
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e",
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+            "00",
+        ),
+        // the nagydani-1 cases of the modexp precompile tests in go-ethereum.
+        (
+            NAGYDANI_1_BASE,
+            "02",
+            NAGYDANI_1_MODULUS,
+            "60008f1614cc01dcfb6bfb09c625cf90b47d4468db81b5f8b7a39d42f332eab9b2da8f2d95311648a8f243f4bb13cfb3d8f7f2a3c014122ebb3ed41b02783adc",
+        ),
+        (
+            NAGYDANI_1_BASE,
+            "03",
+            NAGYDANI_1_MODULUS,
+            "4834a46ba565db27903b1c720c9d593e84e4cbd6ad2e64b31885d944f68cd801f92225a8961c952ddf2797fa4701b330c85c4b363798100b921a1a22a46a7fec",
+        ),
+        (
+            NAGYDANI_1_BASE,
+            "010001",
+            NAGYDANI_1_MODULUS,
+            "c36d804180c35d4426b57b50c5bfcca5c01856d104564cd513b461d3c8b8409128a5573e416d0ebe38f5f736766d9dc27143e4da981dfa4d67f7dc474cbee6d2",
+        ),
+    ];
+    for (base, exponent, modulus, expected) in cases {
+        let result = modexp(
+            hex::decode(base).unwrap().as_slice(),
+            hex::decode(exponent).unwrap().as_slice(),
+            hex::decode(modulus).unwrap().as_slice(),
+        )
+        .unwrap();
+        assert_eq!(
+            BigUint::from_bytes_be(result.as_slice()),
+            BigUint::from_bytes_be(hex::decode(expected).unwrap().as_slice()),
+            "{}^{} mod {}",
+            base,
+            exponent,
+            modulus
+        );
+    }
+    assert!(modexp(&[3], &[5], &[]).is_none());
+    assert!(modexp(&[3], &[5], &[0, 0]).is_none());
+}
+
+#[test]
+fn test_modexp_complexity() {
+    assert_eq!(modexp_complexity(1, &[], 1), 1);
+    assert_eq!(modexp_complexity(1, &[0], 1), 1);
+    // 0x010001 has 17 bits.
+    assert_eq!(modexp_complexity(32, &[1, 0, 1], 32), 16 * 16);
+    assert_eq!(modexp_complexity(0, &[1, 0, 1], 64), 64 * 16);
+    // the exponent bytes below the highest 32 bytes are charged as 8 bits each.
+    assert_eq!(modexp_complexity(32, &[0xff; 64], 32), 16 * (8 * 32 + 255));
+    assert_eq!(
+        modexp_complexity(32, &[0u8; 64], 32),
+        16 * (8 * 32),
+        "leading zeros of a long exponent are charged"
+    );
+}
@@ -0,0 +1,166 @@
+use crate::gas_schedule::{starcoin_native_gas, StarcoinNativeCostIndex};
+use crate::sub_status::{
+    NFE_BASE58_DECODE_FAILURE, NFE_BASE58_INPUT_TOO_LONG, NFE_HEX_DECODE_FAILURE,
+};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_schedule::{GasCarrier, InternalGasUnits};
+use move_vm_runtime::native_functions::NativeContext;
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+/// base58 conversion is quadratic to the input length, so the input is bounded.
+pub const MAX_BASE58_INPUT_LENGTH: usize = 1024;
+
+/// Rust implementation of Move's `native public fun base58_encode(data: vector<u8>): vector<u8>;`
+/// Abort with `NFE_BASE58_INPUT_TOO_LONG` if `data` is longer than `MAX_BASE58_INPUT_LENGTH`.
+pub fn native_base58_encode(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let data = pop_arg!(arguments, Vec<u8>);
+    let cost = base58_cost(context, data.len());
+    if data.len() > MAX_BASE58_INPUT_LENGTH {
+        return Ok(NativeResult::err(cost, NFE_BASE58_INPUT_TOO_LONG));
+    }
+    let output = bs58::encode(data).into_vec();
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(output)]))
+}
+
+/// Rust implementation of Move's `native public fun base58_decode(data: vector<u8>): vector<u8>;`
+/// Abort with `NFE_BASE58_DECODE_FAILURE` if `data` contains invalid characters, and with
+/// `NFE_BASE58_INPUT_TOO_LONG` if `data` is longer than `MAX_BASE58_INPUT_LENGTH`.
+pub fn native_base58_decode(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let data = pop_arg!(arguments, Vec<u8>);
+    let cost = base58_cost(context, data.len());
+    if data.len() > MAX_BASE58_INPUT_LENGTH {
+        return Ok(NativeResult::err(cost, NFE_BASE58_INPUT_TOO_LONG));
+    }
+    match bs58::decode(data).into_vec() {
+        Ok(output) => Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(output)])),
+        Err(_) => Ok(NativeResult::err(cost, NFE_BASE58_DECODE_FAILURE)),
+    }
+}
+
+fn base58_cost(context: &NativeContext, len: usize) -> InternalGasUnits<GasCarrier> {
+    // an input longer than the limit is charged as the limit before it aborts.
+    let len = std::cmp::min(len, MAX_BASE58_INPUT_LENGTH);
+    starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::BASE58,
+        len * len,
+    )
+}
+
+/// Rust implementation of Move's `native public fun hex_encode(data: vector<u8>): vector<u8>;`
+/// Return the lowercase hex of `data`, without `0x` prefix.
+pub fn native_hex_encode(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let data = pop_arg!(arguments, Vec<u8>);
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::HEX,
+        data.len(),
+    );
+    let output = hex::encode(data).into_bytes();
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(output)]))
+}
+
+/// Rust implementation of Move's `native public fun hex_decode(data: vector<u8>): vector<u8>;`
+/// Abort with `NFE_HEX_DECODE_FAILURE` if `data` has odd length or contains non-hex characters.
+pub fn native_hex_decode(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let data = pop_arg!(arguments, Vec<u8>);
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::HEX,
+        data.len(),
+    );
+    match hex::decode(data) {
+        Ok(output) => Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(output)])),
+        Err(_) => Ok(NativeResult::err(cost, NFE_HEX_DECODE_FAILURE)),
+    }
+}
+
+/// Rust implementation of Move's `native public fun bech32_decode(data: vector<u8>): (bool, vector<u8>, vector<u8>);`
+/// Return `(true, hrp, data)` if `data` is a valid bech32 string, the returned data is the 5 bits
+/// values, one value per byte. Return `(false, [], [])` for invalid input, including bech32m.
+pub fn native_bech32_decode(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let data = pop_arg!(arguments, Vec<u8>);
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::BECH32,
+        data.len(),
+    );
+    let (valid, hrp, data) = match bech32_decode(data.as_slice()) {
+        Some((hrp, data)) => (true, hrp, data),
+        None => (false, vec![], vec![]),
+    };
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![
+            Value::bool(valid),
+            Value::vector_u8(hrp),
+            Value::vector_u8(data)
+        ],
+    ))
+}
+
+fn bech32_decode(data: &[u8]) -> Option<(Vec<u8>, Vec<u8>)> {
+    let data = std::str::from_utf8(data).ok()?;
+    match bech32::decode(data).ok()? {
+        (hrp, data, bech32::Variant::Bech32) => Some((
+            hrp.into_bytes(),
+            data.into_iter().map(|v| v.to_u8()).collect(),
+        )),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_bech32_decode() {
+    // vectors from BIP-173.
+    assert_eq!(bech32_decode(b"A12UEL5L"), Some((b"a".to_vec(), vec![])));
+    assert_eq!(
+        bech32_decode(b"abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw"),
+        Some((b"abcdef".to_vec(), (0u8..32).collect()))
+    );
+    assert_eq!(
+        bech32_decode(b"abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxx"),
+        None
+    );
+    assert_eq!(bech32_decode(b"A12UEL5M"), None);
+    assert_eq!(bech32_decode(&[0xff, 0xfe]), None);
+}
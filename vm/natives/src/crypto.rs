@@ -0,0 +1,388 @@
+use crate::gas_schedule::{starcoin_native_gas, StarcoinNativeCostIndex};
+use crate::sub_status::{
+    NFE_AES_GCM_INVALID_KEY_LENGTH, NFE_BN254_INVALID_G1_POINT, NFE_GROTH16_INVALID_VERIFYING_KEY,
+    NFE_GROTH16_TOO_MANY_PUBLIC_INPUTS,
+};
+use aes_gcm::aead::{AeadInPlace, NewAead};
+use aes_gcm::{Aes128Gcm, Aes256Gcm, Key, Nonce, Tag};
+use bn::{pairing_batch, AffineG1, AffineG2, Fq, Fq2, Fr, Group, Gt, G1, G2};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_schedule::GasAlgebra;
+use move_vm_runtime::native_functions::NativeContext;
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+const AES_GCM_NONCE_LENGTH: usize = 12;
+const AES_GCM_TAG_LENGTH: usize = 16;
+
+/// Rust implementation of Move's
+/// `native public fun aes_gcm_verify(key: vector<u8>, nonce: vector<u8>, ciphertext: vector<u8>, aad: vector<u8>, tag: vector<u8>): bool;`
+/// `key` must be 16 or 32 bytes for AES-128-GCM or AES-256-GCM, otherwise abort.
+/// Return false if the nonce is not 12 bytes, the tag is not 16 bytes, or the tag is invalid.
+pub fn native_aes_gcm_verify(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 5);
+
+    let tag = pop_arg!(arguments, Vec<u8>);
+    let aad = pop_arg!(arguments, Vec<u8>);
+    let ciphertext = pop_arg!(arguments, Vec<u8>);
+    let nonce = pop_arg!(arguments, Vec<u8>);
+    let key = pop_arg!(arguments, Vec<u8>);
+
+    // both the ciphertext and the aad go through GHASH.
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::AES_GCM_VERIFY,
+        ciphertext.len() + aad.len(),
+    );
+    match aes_gcm_verify(&key, &nonce, &ciphertext, &aad, &tag) {
+        Some(valid) => Ok(NativeResult::ok(cost, smallvec![Value::bool(valid)])),
+        None => Ok(NativeResult::err(cost, NFE_AES_GCM_INVALID_KEY_LENGTH)),
+    }
+}
+
+/// Return None if the key length is invalid.
+fn aes_gcm_verify(
+    key: &[u8],
+    nonce: &[u8],
+    ciphertext: &[u8],
+    aad: &[u8],
+    tag: &[u8],
+) -> Option<bool> {
+    if key.len() != 16 && key.len() != 32 {
+        return None;
+    }
+    if nonce.len() != AES_GCM_NONCE_LENGTH || tag.len() != AES_GCM_TAG_LENGTH {
+        return Some(false);
+    }
+    let nonce = Nonce::from_slice(nonce);
+    let tag = Tag::from_slice(tag);
+    let mut buffer = ciphertext.to_vec();
+    let result = if key.len() == 16 {
+        Aes128Gcm::new(Key::from_slice(key)).decrypt_in_place_detached(nonce, aad, &mut buffer, tag)
+    } else {
+        Aes256Gcm::new(Key::from_slice(key)).decrypt_in_place_detached(nonce, aad, &mut buffer, tag)
+    };
+    Some(result.is_ok())
+}
+
+/// A compressed G1 point is a flag byte, 2 for even y or 3 for odd y, followed by the 32 bytes
+/// big-endian x, and the point at infinity is 33 zero bytes.
+const BN254_G1_COMPRESSED_LENGTH: usize = 33;
+
+/// Rust implementation of Move's `native public fun bn254_g1_add(a: vector<u8>, b: vector<u8>): vector<u8>;`
+/// Return the compressed sum of the compressed G1 points `a` and `b`, abort if any of them is
+/// not a point on the curve.
+pub fn native_bn254_g1_add(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let b = pop_arg!(arguments, Vec<u8>);
+    let a = pop_arg!(arguments, Vec<u8>);
+
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::BN254_G1_ADD,
+        1,
+    );
+    match bn254_g1_add(&a, &b) {
+        Some(sum) => Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(sum)])),
+        None => Ok(NativeResult::err(cost, NFE_BN254_INVALID_G1_POINT)),
+    }
+}
+
+/// Return None if any point is invalid.
+fn bn254_g1_add(a: &[u8], b: &[u8]) -> Option<Vec<u8>> {
+    let sum = bn254_g1_decompress(a)? + bn254_g1_decompress(b)?;
+    Some(bn254_g1_compress(sum))
+}
+
+fn bn254_g1_decompress(bytes: &[u8]) -> Option<G1> {
+    if bytes.len() != BN254_G1_COMPRESSED_LENGTH {
+        return None;
+    }
+    if bytes.iter().all(|b| *b == 0) {
+        return Some(G1::zero());
+    }
+    G1::from_compressed(bytes).ok()
+}
+
+fn bn254_g1_compress(point: G1) -> Vec<u8> {
+    let mut output = vec![0u8; BN254_G1_COMPRESSED_LENGTH];
+    if let Some(affine) = AffineG1::from_jacobian(point) {
+        let y_is_odd = affine.y().into_u256().get_bit(0).unwrap_or(false);
+        output[0] = if y_is_odd { 3 } else { 2 };
+        affine
+            .x()
+            .to_big_endian(&mut output[1..])
+            .expect("the output has 32 bytes for x.");
+    }
+    output
+}
+
+/// An uncompressed G1 point is the 32 bytes big-endian x and y, and the point at infinity is
+/// all zero, same as the alt_bn128 precompiles of ethereum.
+const BN254_G1_LENGTH: usize = 64;
+/// An uncompressed G2 point is the imaginary and real parts of x, then of y.
+const BN254_G2_LENGTH: usize = 128;
+const BN254_FR_LENGTH: usize = 32;
+/// alpha_g1, beta_g2, gamma_g2 and delta_g2, followed by the ic points.
+const GROTH16_VK_FIXED_LENGTH: usize = BN254_G1_LENGTH + 3 * BN254_G2_LENGTH;
+/// a in G1, b in G2 and c in G1.
+const GROTH16_PROOF_LENGTH: usize = 2 * BN254_G1_LENGTH + BN254_G2_LENGTH;
+/// The max count of public inputs, each of them costs a scalar multiplication.
+pub const MAX_GROTH16_PUBLIC_INPUTS: usize = 64;
+
+/// Rust implementation of Move's `native public fun groth16_verify(verifying_key: vector<u8>, proof: vector<u8>, public_inputs: vector<u8>): bool;`
+/// Verify a Groth16 proof over bn254. `public_inputs` is the concatenated 32 bytes big-endian
+/// field elements. Abort with `NFE_GROTH16_INVALID_VERIFYING_KEY` if the verifying key is
+/// malformed, and with `NFE_GROTH16_TOO_MANY_PUBLIC_INPUTS` if the verifying key has more than
+/// `MAX_GROTH16_PUBLIC_INPUTS` public inputs. Return false if the proof or the public inputs
+/// are malformed.
+pub fn native_groth16_verify(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let public_inputs = pop_arg!(arguments, Vec<u8>);
+    let proof = pop_arg!(arguments, Vec<u8>);
+    let verifying_key = pop_arg!(arguments, Vec<u8>);
+
+    // the pairings are fixed, and every public input adds a point decoding and a scalar multiplication.
+    let input_count = (verifying_key.len().saturating_sub(GROTH16_VK_FIXED_LENGTH)
+        / BN254_G1_LENGTH)
+        .saturating_sub(1);
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::GROTH16_VERIFY,
+        1,
+    )
+    .add(starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::GROTH16_PUBLIC_INPUT,
+        std::cmp::min(input_count, MAX_GROTH16_PUBLIC_INPUTS),
+    ));
+    if input_count > MAX_GROTH16_PUBLIC_INPUTS {
+        return Ok(NativeResult::err(cost, NFE_GROTH16_TOO_MANY_PUBLIC_INPUTS));
+    }
+    let verifying_key = match Groth16VerifyingKey::decode(&verifying_key) {
+        Some(verifying_key) => verifying_key,
+        None => return Ok(NativeResult::err(cost, NFE_GROTH16_INVALID_VERIFYING_KEY)),
+    };
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::bool(groth16_verify(
+            &verifying_key,
+            &proof,
+            &public_inputs
+        ))],
+    ))
+}
+
+struct Groth16VerifyingKey {
+    alpha_g1: G1,
+    beta_g2: G2,
+    gamma_g2: G2,
+    delta_g2: G2,
+    /// The first one is the constant term, the others are for the public inputs.
+    ic: Vec<G1>,
+}
+
+impl Groth16VerifyingKey {
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < GROTH16_VK_FIXED_LENGTH + BN254_G1_LENGTH
+            || (bytes.len() - GROTH16_VK_FIXED_LENGTH) % BN254_G1_LENGTH != 0
+        {
+            return None;
+        }
+        let (alpha_g1, rest) = bytes.split_at(BN254_G1_LENGTH);
+        let (beta_g2, rest) = rest.split_at(BN254_G2_LENGTH);
+        let (gamma_g2, rest) = rest.split_at(BN254_G2_LENGTH);
+        let (delta_g2, ic) = rest.split_at(BN254_G2_LENGTH);
+        Some(Self {
+            alpha_g1: bn254_g1_decode(alpha_g1)?,
+            beta_g2: bn254_g2_decode(beta_g2)?,
+            gamma_g2: bn254_g2_decode(gamma_g2)?,
+            delta_g2: bn254_g2_decode(delta_g2)?,
+            ic: ic
+                .chunks(BN254_G1_LENGTH)
+                .map(bn254_g1_decode)
+                .collect::<Option<Vec<_>>>()?,
+        })
+    }
+}
+
+/// Check e(a, b) == e(alpha, beta) * e(ic[0] + sum(input[i] * ic[i + 1]), gamma) * e(c, delta).
+fn groth16_verify(verifying_key: &Groth16VerifyingKey, proof: &[u8], public_inputs: &[u8]) -> bool {
+    if proof.len() != GROTH16_PROOF_LENGTH
+        || public_inputs.len() != (verifying_key.ic.len() - 1) * BN254_FR_LENGTH
+    {
+        return false;
+    }
+    let (a, rest) = proof.split_at(BN254_G1_LENGTH);
+    let (b, c) = rest.split_at(BN254_G2_LENGTH);
+    let (a, b, c) = match (bn254_g1_decode(a), bn254_g2_decode(b), bn254_g1_decode(c)) {
+        (Some(a), Some(b), Some(c)) => (a, b, c),
+        _ => return false,
+    };
+    let mut acc = verifying_key.ic[0];
+    for (input, ic) in public_inputs
+        .chunks(BN254_FR_LENGTH)
+        .zip(verifying_key.ic.iter().skip(1))
+    {
+        match Fr::from_slice(input) {
+            Ok(input) => acc = acc + *ic * input,
+            Err(_) => return false,
+        }
+    }
+    pairing_batch(&[
+        (-a, b),
+        (verifying_key.alpha_g1, verifying_key.beta_g2),
+        (acc, verifying_key.gamma_g2),
+        (c, verifying_key.delta_g2),
+    ]) == Gt::one()
+}
+
+fn bn254_g1_decode(bytes: &[u8]) -> Option<G1> {
+    let x = Fq::from_slice(&bytes[..32]).ok()?;
+    let y = Fq::from_slice(&bytes[32..64]).ok()?;
+    if x == Fq::zero() && y == Fq::zero() {
+        return Some(G1::zero());
+    }
+    AffineG1::new(x, y).ok().map(Into::into)
+}
+
+fn bn254_g2_decode(bytes: &[u8]) -> Option<G2> {
+    let x = Fq2::new(
+        Fq::from_slice(&bytes[32..64]).ok()?,
+        Fq::from_slice(&bytes[..32]).ok()?,
+    );
+    let y = Fq2::new(
+        Fq::from_slice(&bytes[96..128]).ok()?,
+        Fq::from_slice(&bytes[64..96]).ok()?,
+    );
+    if x == Fq2::zero() && y == Fq2::zero() {
+        return Some(G2::zero());
+    }
+    AffineG2::new(x, y).ok().map(Into::into)
+}
+
+#[test]
+fn test_aes_gcm_verify() {
+    // generated with OpenSSL: plaintext "starcoin", aad "aad", key 0x00..0x0f or 0x00..0x1f, nonce 0x00..0x0b.
+    let nonce = (0u8..12).collect::<Vec<_>>();
+    let cases = vec![
+        (16u8, "e018c6bc05749e3a", "a7fde32fbad1491b5372cabb22dd3c2d"),
+        (32u8, "3476b769a68aab75", "f902b83f9815d285165bcff7ca3b4433"),
+    ];
+    for (key_len, ciphertext, tag) in cases {
+        let key = (0u8..key_len).collect::<Vec<_>>();
+        let ciphertext = hex::decode(ciphertext).unwrap();
+        let tag = hex::decode(tag).unwrap();
+        assert_eq!(
+            aes_gcm_verify(&key, &nonce, &ciphertext, b"aad", &tag),
+            Some(true)
+        );
+        assert_eq!(
+            aes_gcm_verify(&key, &nonce, &ciphertext, b"bad", &tag),
+            Some(false)
+        );
+        assert_eq!(
+            aes_gcm_verify(&key, &nonce[1..], &ciphertext, b"aad", &tag),
+            Some(false)
+        );
+        assert_eq!(
+            aes_gcm_verify(&key[1..], &nonce, &ciphertext, b"aad", &tag),
+            None
+        );
+    }
+}
+
+#[test]
+fn test_bn254_g1_add() {
+    let infinity = vec![0u8; BN254_G1_COMPRESSED_LENGTH];
+    // the generator (1, 2) has even y.
+    let mut generator = vec![0u8; BN254_G1_COMPRESSED_LENGTH];
+    generator[0] = 2;
+    generator[BN254_G1_COMPRESSED_LENGTH - 1] = 1;
+    // 2 * (1, 2), x = 0x030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3
+    let double =
+        hex::decode("02030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3").unwrap();
+    // -(1, 2) has odd y.
+    let mut negative = generator.clone();
+    negative[0] = 3;
+
+    assert_eq!(bn254_g1_add(&generator, &generator).unwrap(), double);
+    assert_eq!(bn254_g1_add(&generator, &infinity).unwrap(), generator);
+    assert_eq!(bn254_g1_add(&infinity, &infinity).unwrap(), infinity);
+    assert_eq!(bn254_g1_add(&generator, &negative).unwrap(), infinity);
+
+    // x = 0 is not on the curve, y^2 = 3 has no root.
+    let mut invalid = vec![0u8; BN254_G1_COMPRESSED_LENGTH];
+    invalid[0] = 2;
+    assert!(bn254_g1_add(&generator, &invalid).is_none());
+    assert!(bn254_g1_add(&generator, &generator[1..]).is_none());
+}
+
+#[test]
+fn test_groth16_verify() {
+    // The key and the proof are built from known scalars, so they satisfy the pairing check:
+    // alpha = 5, beta = 7, gamma = 11, delta = 13, ic = [17, 19, 23], inputs = [3, 4],
+    // a = 29, b = 31 and c = (a * b - alpha * beta - (17 + 19 * 3 + 23 * 4) * gamma) / delta.
+    let verifying_key = hex::decode(concat!(
+        "17c139df0efee0f766bc0204762b774362e4ded88953a39ce849a8a7fa163fa901e0559bacb160664764a357af8a9fe70baa9258e0b959273ffc5718c6d4cc7c",
+        "2903ba015a9abde26a5d081e84551e63be0fd4516e46ee6d593edeba46362455224bdc5d4327fcf8ed702e01de1c2f1657a253ba75e32a89c390142aaa28b30803c8b7cda6b2dedb7aeeaf5fda464ad17036bea1c4e6f7adbaed1ebe0335e0d81d92fff52a265017eeccb372e37d7a7bd431800eca28dfd82e21e8054114233f",
+        "228b515a17f28b89920873207477f8c7fc05582debaf3184febf1cfdedc5ce8812bb1156a9f6b360fcb2614e15d8a3ff07f2c699dc69ca830b20d2df91fe9cd32b15dc62a5c9e36597914ddbbfde48806a8eabe45c8d3cccf9578ad08e058f9202a4fd764f52470e2fcfff325fb9692f55d6b8b077eefeaa04e07152b4d1fa94",
+        "009edaf0698a8c56f51139588acc094cee3c37d427bb6d2eab830aae529097d123ad66f3a7cca9dc75049635faebd124316244b91de5fb2764cd151572a905f72700e8a29b7bb45f3022a18a07bdc66d0254559e17cce64e3b4ad21578fcf4101ad4f87d3b4375a39988ac099b042b1e7c0c715678e4c2bea8905f607cf950f8",
+        "1c6a451060210f3baad93fe1631753751da9857edae0468e8e4bee7dd33cfb2c2331a64aa86c50d2d1e0237893ef7744a77228881ce73fcc2ad555a37d4ab405",
+        "15514de6a136158ef7b2bc22bed59866743bc401edd63ae857d44f4c71edc28d095e28f5ba5d73440c0e504b624afabfedb9387320817b62e9168b6868d8952e",
+        "1e28260f0ee971dec1e84cf81ff2776ad314d2cfb9ef81d4c970620c29b811f128fc8a72d4ff12654c3c39dab54eaef9638d28de738959779fcd3e7ac918b396",
+    ))
+    .unwrap();
+    let proof = hex::decode(concat!(
+        "1605ffc1ea2e1aef15d774d3207176420c5cc454b19b55558562b0c7ddf00a7d0cf605873faa8028df38ec2d0800d5ddc67f1776338d675491fe87f6bb7354b3",
+        "2aeec44fe814d216dbf395d867287888ff68759bb9d5a0167109501aa9eb15dd1ae8bf2213b5f02d55e2ef7cb6aa28e4a5c0b10f8145e5f3e365e5d6e7938e99012ba32451dff8aba8f20e5294fc0e4a53f9dabbfcabe7e595bf95abdfa25be8033ccc1affd4d27701ea9dc524dd3e7a8848bcae31f9eeade63b613d662fd900",
+        "2a0205f9789c8252ebcab5a047226290d9c6ba7405d89eb506e73e0ddbb7734d1a726a5cb07aa6bd7fe8c8889112f712c124f6ea79f3f52ad8d54cc4b92a6934",
+    ))
+    .unwrap();
+    let mut public_inputs = vec![0u8; 2 * BN254_FR_LENGTH];
+    public_inputs[BN254_FR_LENGTH - 1] = 3;
+    public_inputs[2 * BN254_FR_LENGTH - 1] = 4;
+
+    let key = Groth16VerifyingKey::decode(&verifying_key).unwrap();
+    assert_eq!(key.ic.len(), 3);
+    assert!(groth16_verify(&key, &proof, &public_inputs));
+
+    let mut wrong_inputs = public_inputs.clone();
+    wrong_inputs[2 * BN254_FR_LENGTH - 1] = 5;
+    assert!(!groth16_verify(&key, &proof, &wrong_inputs));
+    assert!(!groth16_verify(
+        &key,
+        &proof,
+        &public_inputs[..BN254_FR_LENGTH]
+    ));
+    let mut wrong_proof = proof.clone();
+    wrong_proof[GROTH16_PROOF_LENGTH - 1] ^= 1;
+    assert!(!groth16_verify(&key, &wrong_proof, &public_inputs));
+
+    // malformed verifying keys.
+    assert!(Groth16VerifyingKey::decode(&verifying_key[..verifying_key.len() - 1]).is_none());
+    assert!(Groth16VerifyingKey::decode(&verifying_key[..GROTH16_VK_FIXED_LENGTH]).is_none());
+    let mut off_curve = verifying_key.clone();
+    off_curve[BN254_G1_LENGTH - 1] ^= 1;
+    assert!(Groth16VerifyingKey::decode(&off_curve).is_none());
+}
@@ -0,0 +1,27 @@
+//! Gas cost of the natives implemented by starcoin, which are indexed by `StarcoinNativeCostIndex`.
+
+use move_core_types::gas_schedule::{
+    AbstractMemorySize, GasAlgebra, GasCarrier, GasCost, InternalGasUnits,
+};
+use move_vm_types::gas_schedule::CostTable;
+use once_cell::sync::Lazy;
+pub use starcoin_vm_types::gas_schedule::StarcoinNativeCostIndex;
+use starcoin_vm_types::on_chain_config::latest_native_table;
+
+static LATEST_NATIVE_TABLE: Lazy<Vec<GasCost>> = Lazy::new(latest_native_table);
+
+/// Same as `move_vm_types::natives::function::native_gas`, but for the starcoin natives.
+/// The on chain native table of a network created before a native was added does not have its
+/// cost, use the cost of the latest native table until a gas schedule upgrade adds it.
+pub fn starcoin_native_gas(
+    table: &CostTable,
+    key: StarcoinNativeCostIndex,
+    size: usize,
+) -> InternalGasUnits<GasCarrier> {
+    let gas_cost = table
+        .native_table
+        .get(key as usize)
+        .unwrap_or_else(|| &LATEST_NATIVE_TABLE[key as usize]);
+    let memory_size = AbstractMemorySize::new(std::cmp::max(1, size) as GasCarrier);
+    gas_cost.total().mul(memory_size)
+}
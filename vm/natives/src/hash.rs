@@ -1,3 +1,10 @@
+use crate::gas_schedule::{starcoin_native_gas, StarcoinNativeCostIndex};
+use crate::sub_status::{
+    NFE_MERKLE_PROOF_INVALID_LEAF_LENGTH, NFE_MERKLE_PROOF_MALFORMED, NFE_MERKLE_ROOT_EMPTY_LEAVES,
+    NFE_MERKLE_ROOT_INVALID_LEAF_LENGTH, NFE_POSEIDON_INVALID_FIELD_ELEMENT,
+    NFE_POSEIDON_INVALID_INPUT_COUNT, NFE_SIPHASH_INVALID_KEY_LENGTH,
+};
+use ff::{PrimeField, PrimeFieldRepr};
 use move_binary_format::errors::PartialVMResult;
 use move_vm_runtime::native_functions::NativeContext;
 use move_vm_types::{
@@ -7,8 +14,14 @@ use move_vm_types::{
     pop_arg,
     values::Value,
 };
+use once_cell::sync::Lazy;
+use poseidon_rs::{Fr, FrRepr, Poseidon};
+use siphasher::sip::SipHasher24;
 use smallvec::smallvec;
+use starcoin_crypto::HashValue;
 use std::collections::VecDeque;
+use std::convert::TryInto;
+use std::hash::Hasher as _;
 use tiny_keccak::Hasher;
 
 pub fn native_keccak_256(
@@ -36,3 +49,466 @@ pub fn native_keccak_256(
 
     Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(output)]))
 }
+
+pub fn native_keccak_512(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let hash_arg = pop_arg!(arguments, Vec<u8>);
+
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::KECCAK_512,
+        hash_arg.len(),
+    );
+    let output = keccak_512(hash_arg.as_slice());
+
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(output)]))
+}
+
+fn keccak_512(data: &[u8]) -> Vec<u8> {
+    let mut output = [0u8; 64];
+    let mut keccak = tiny_keccak::Keccak::v512();
+    keccak.update(data);
+    keccak.finalize(&mut output);
+    output.to_vec()
+}
+
+pub fn native_blake2b_256(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let hash_arg = pop_arg!(arguments, Vec<u8>);
+
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::BLAKE2B_256,
+        hash_arg.len(),
+    );
+    let output = blake2b_256(hash_arg.as_slice());
+
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(output)]))
+}
+
+fn blake2b_256(data: &[u8]) -> Vec<u8> {
+    use blake2::digest::{Update, VariableOutput};
+    let mut output = vec![0u8; 32];
+    let mut blake2b = blake2::VarBlake2b::new(32).expect("32 is a valid blake2b output size");
+    blake2b.update(data);
+    blake2b.finalize_variable(|res| output.copy_from_slice(res));
+    output
+}
+
+pub fn native_ripemd160(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let hash_arg = pop_arg!(arguments, Vec<u8>);
+
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::RIPEMD160,
+        hash_arg.len(),
+    );
+    let output = ripemd160(hash_arg.as_slice());
+
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(output)]))
+}
+
+fn ripemd160(data: &[u8]) -> Vec<u8> {
+    use ripemd160::Digest;
+    ripemd160::Ripemd160::digest(data).to_vec()
+}
+
+/// Rust implementation of Move's `native public fun merkle_root_sha3(leaves: vector<vector<u8>>): vector<u8>;`
+/// Internal nodes are `sha3_256(left || right)`, the last node of a level with odd count is
+/// duplicated. Abort with `NFE_MERKLE_ROOT_EMPTY_LEAVES` if `leaves` is empty, and with
+/// `NFE_MERKLE_ROOT_INVALID_LEAF_LENGTH` if any leaf is not a 32 bytes hash.
+pub fn native_merkle_root_sha3(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let leaves = pop_arg!(arguments, Vec<Value>)
+        .into_iter()
+        .map(|leaf| leaf.value_as::<Vec<u8>>())
+        .collect::<PartialVMResult<Vec<_>>>()?;
+
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::MERKLE_ROOT_SHA3,
+        leaves.len(),
+    );
+    // the cost is per node, so every node must be a hash of fixed length.
+    if leaves.iter().any(|leaf| leaf.len() != HashValue::LENGTH) {
+        return Ok(NativeResult::err(cost, NFE_MERKLE_ROOT_INVALID_LEAF_LENGTH));
+    }
+    match merkle_root_sha3(leaves) {
+        Some(root) => Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(root)])),
+        None => Ok(NativeResult::err(cost, NFE_MERKLE_ROOT_EMPTY_LEAVES)),
+    }
+}
+
+fn merkle_root_sha3(mut nodes: Vec<Vec<u8>>) -> Option<Vec<u8>> {
+    while nodes.len() > 1 {
+        if nodes.len() % 2 == 1 {
+            nodes.push(nodes[nodes.len() - 1].clone());
+        }
+        nodes = nodes
+            .chunks(2)
+            .map(|pair| {
+                HashValue::sha3_256_of(&[pair[0].as_slice(), pair[1].as_slice()].concat()).to_vec()
+            })
+            .collect();
+    }
+    nodes.pop()
+}
+
+/// Verify `leaf` is included in the merkle tree of `root` built by `merkle_root_sha3`.
+/// `proof` is a sequence of 33 bytes items from the leaf level up,
+/// each item is a flag byte (1 if the sibling is the left node, else 0) followed by the 32 bytes sibling hash.
+/// Abort with `NFE_MERKLE_PROOF_INVALID_LEAF_LENGTH` if `leaf` is not a 32 bytes hash.
+pub fn native_verify_merkle_proof_sha3(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let proof = pop_arg!(arguments, Vec<u8>);
+    let leaf = pop_arg!(arguments, Vec<u8>);
+    let root = pop_arg!(arguments, Vec<u8>);
+
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::VERIFY_MERKLE_PROOF_SHA3,
+        proof.len() / MERKLE_PROOF_ITEM_LENGTH,
+    );
+    // the cost is per proof item, so the leaf must be a hash of fixed length as the siblings.
+    if leaf.len() != HashValue::LENGTH {
+        return Ok(NativeResult::err(
+            cost,
+            NFE_MERKLE_PROOF_INVALID_LEAF_LENGTH,
+        ));
+    }
+    match verify_merkle_proof_sha3(root.as_slice(), leaf, proof.as_slice()) {
+        Some(result) => Ok(NativeResult::ok(cost, smallvec![Value::bool(result)])),
+        None => Ok(NativeResult::err(cost, NFE_MERKLE_PROOF_MALFORMED)),
+    }
+}
+
+const MERKLE_PROOF_ITEM_LENGTH: usize = 1 + HashValue::LENGTH;
+
+/// Return None if the proof is malformed.
+fn verify_merkle_proof_sha3(root: &[u8], leaf: Vec<u8>, proof: &[u8]) -> Option<bool> {
+    if proof.len() % MERKLE_PROOF_ITEM_LENGTH != 0 {
+        return None;
+    }
+    let mut node = leaf;
+    for item in proof.chunks(MERKLE_PROOF_ITEM_LENGTH) {
+        let sibling = &item[1..];
+        node = match item[0] {
+            1 => HashValue::sha3_256_of(&[sibling, node.as_slice()].concat()).to_vec(),
+            0 => HashValue::sha3_256_of(&[node.as_slice(), sibling].concat()).to_vec(),
+            _ => return None,
+        };
+    }
+    Some(node.as_slice() == root)
+}
+
+pub fn native_siphash(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let message = pop_arg!(arguments, Vec<u8>);
+    let key = pop_arg!(arguments, Vec<u8>);
+
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::SIPHASH,
+        message.len(),
+    );
+    match siphash(key.as_slice(), message.as_slice()) {
+        Some(hash) => Ok(NativeResult::ok(cost, smallvec![Value::u64(hash)])),
+        None => Ok(NativeResult::err(cost, NFE_SIPHASH_INVALID_KEY_LENGTH)),
+    }
+}
+
+fn siphash(key: &[u8], message: &[u8]) -> Option<u64> {
+    let key: [u8; 16] = key.try_into().ok()?;
+    let mut hasher = SipHasher24::new_with_key(&key);
+    hasher.write(message);
+    Some(hasher.finish())
+}
+
+/// Rust implementation of Move's `native public fun crc32(data: vector<u8>): u64;`
+/// The IEEE CRC32 checksum of `data`.
+pub fn native_crc32(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let data = pop_arg!(arguments, Vec<u8>);
+
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::CRC32,
+        data.len(),
+    );
+    let checksum = crc32fast::hash(data.as_slice());
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::u64(checksum as u64)],
+    ))
+}
+
+/// The max count of inputs supported by the circomlib compatible poseidon parameters.
+const POSEIDON_MAX_INPUTS: usize = 16;
+const POSEIDON_FIELD_ELEMENT_LENGTH: usize = 32;
+/// The round constants and MDS matrices are parsed from strings, so build them only once.
+static POSEIDON: Lazy<Poseidon> = Lazy::new(Poseidon::new);
+
+/// Rust implementation of Move's `native public fun poseidon_bn254(inputs: vector<vector<u8>>): vector<u8>;`
+/// Each input is a 32 bytes big-endian element of the BN254 scalar field, the hash is compatible
+/// with circomlib and returned in the same encoding.
+/// Abort if an input is not a valid field element, or the input count is not in 1..=16.
+pub fn native_poseidon_bn254(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let inputs = pop_arg!(arguments, Vec<Value>)
+        .into_iter()
+        .map(|i| i.value_as::<Vec<u8>>())
+        .collect::<PartialVMResult<Vec<_>>>()?;
+
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::POSEIDON_BN254,
+        inputs.len(),
+    );
+    if inputs.is_empty() || inputs.len() > POSEIDON_MAX_INPUTS {
+        return Ok(NativeResult::err(cost, NFE_POSEIDON_INVALID_INPUT_COUNT));
+    }
+    match poseidon_bn254(&inputs) {
+        Some(hash) => Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(hash)])),
+        None => Ok(NativeResult::err(cost, NFE_POSEIDON_INVALID_FIELD_ELEMENT)),
+    }
+}
+
+/// Return None if any input is not a valid field element.
+fn poseidon_bn254(inputs: &[Vec<u8>]) -> Option<Vec<u8>> {
+    let elements = inputs
+        .iter()
+        .map(|input| {
+            if input.len() != POSEIDON_FIELD_ELEMENT_LENGTH {
+                return None;
+            }
+            let mut repr = FrRepr::default();
+            repr.read_be(input.as_slice()).ok()?;
+            // reject the values not less than the modulus.
+            Fr::from_repr(repr).ok()
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let hash = POSEIDON.hash(elements).ok()?;
+    let mut output = Vec::with_capacity(POSEIDON_FIELD_ELEMENT_LENGTH);
+    hash.into_repr().write_be(&mut output).ok()?;
+    Some(output)
+}
+
+#[test]
+fn test_blake2b_256() {
+    let cases = vec![
+        (
+            "",
+            "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8",
+        ),
+        (
+            "abc",
+            "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319",
+        ),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(
+            hex::encode(blake2b_256(input.as_bytes())),
+            expected,
+            "blake2b_256 of {:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_ripemd160() {
+    let cases = vec![
+        ("", "9c1185a5c5e9fc54612808977ee8f548b2258d31"),
+        ("abc", "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(
+            hex::encode(ripemd160(input.as_bytes())),
+            expected,
+            "ripemd160 of {:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_keccak_512() {
+    let cases = vec![
+        (
+            "",
+            "0eab42de4c3ceb9235fc91acffe746b29c29a8c366b7c60e4e67c466f36a4304c00fa9caf9d87976ba469bcbe06713b435f091ef2769fb160cdab33d3670680e",
+        ),
+        (
+            "abc",
+            "18587dc2ea106b9a1563e32b3312421ca164c7f1f07bc922a9c83d77cea3a1e5d0c69910739025372dc14ac9642629379540c17e2a65b19d77aa511a9d00bb96",
+        ),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(
+            hex::encode(keccak_512(input.as_bytes())),
+            expected,
+            "keccak_512 of {:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_merkle_root_sha3() {
+    let leaves = vec!["a", "b", "c", "d"]
+        .into_iter()
+        .map(|leaf| HashValue::sha3_256_of(leaf.as_bytes()).to_vec())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        hex::encode(merkle_root_sha3(leaves.clone()).unwrap()),
+        "5267fec4a5327f9d287233f95213afa39d3aad2fee1fa1384b032b79fb3441e8"
+    );
+    assert_eq!(
+        hex::encode(merkle_root_sha3(leaves[..3].to_vec()).unwrap()),
+        "78c7c394d3158c218916b7ae0ebdea502e0f4e85c08e3b371e3dfd824d389fa3"
+    );
+    assert_eq!(merkle_root_sha3(leaves[..1].to_vec()).unwrap(), leaves[0]);
+    assert!(merkle_root_sha3(vec![]).is_none());
+}
+
+#[test]
+fn test_siphash() {
+    // reference test vectors of SipHash-2-4 with key 00..0f and message 00..(len-1).
+    let key: Vec<u8> = (0u8..16).collect();
+    let cases = vec![
+        (0, 0x726fdb47dd0e0e31u64),
+        (1, 0x74f839c593dc67fd),
+        (8, 0x93f5f5799a932462),
+        (15, 0xa129ca6149be45e5),
+    ];
+    for (len, expected) in cases {
+        let message: Vec<u8> = (0u8..len).collect();
+        assert_eq!(
+            siphash(key.as_slice(), message.as_slice()),
+            Some(expected),
+            "siphash of message len {}",
+            len
+        );
+    }
+    assert_eq!(siphash(&key[..15], b"abc"), None);
+}
+
+#[test]
+fn test_verify_merkle_proof_sha3() {
+    let leaves = vec!["a", "b", "c"]
+        .into_iter()
+        .map(|leaf| HashValue::sha3_256_of(leaf.as_bytes()).to_vec())
+        .collect::<Vec<_>>();
+    let root = merkle_root_sha3(leaves.clone()).unwrap();
+    let proof_item = |is_left: u8, sibling: &[u8]| [&[is_left][..], sibling].concat();
+
+    // proof of "b": "a" is the left sibling, then the right sibling is hash of "c" with itself.
+    let node_cc = HashValue::sha3_256_of(&[leaves[2].as_slice(), leaves[2].as_slice()].concat());
+    let proof = [
+        proof_item(1, leaves[0].as_slice()),
+        proof_item(0, node_cc.as_slice()),
+    ]
+    .concat();
+    assert_eq!(
+        verify_merkle_proof_sha3(root.as_slice(), leaves[1].clone(), proof.as_slice()),
+        Some(true)
+    );
+    assert_eq!(
+        verify_merkle_proof_sha3(root.as_slice(), leaves[0].clone(), proof.as_slice()),
+        Some(false)
+    );
+    // a single leaf tree has an empty proof.
+    assert_eq!(
+        verify_merkle_proof_sha3(leaves[0].as_slice(), leaves[0].clone(), &[]),
+        Some(true)
+    );
+    // malformed proof: bad length and bad flag.
+    assert_eq!(
+        verify_merkle_proof_sha3(root.as_slice(), leaves[1].clone(), &proof[..32]),
+        None
+    );
+    let mut bad_flag = proof.clone();
+    bad_flag[0] = 2;
+    assert_eq!(
+        verify_merkle_proof_sha3(root.as_slice(), leaves[1].clone(), bad_flag.as_slice()),
+        None
+    );
+}
+
+#[test]
+fn test_crc32() {
+    assert_eq!(crc32fast::hash(b"123456789"), 0xCBF4_3926);
+    assert_eq!(crc32fast::hash(b""), 0);
+}
+
+#[test]
+fn test_poseidon_bn254() {
+    // vectors of circomlib poseidon.
+    let field_element = |v: u8| {
+        let mut element = vec![0u8; POSEIDON_FIELD_ELEMENT_LENGTH];
+        element[POSEIDON_FIELD_ELEMENT_LENGTH - 1] = v;
+        element
+    };
+    assert_eq!(
+        poseidon_bn254(&[field_element(1), field_element(2)]).unwrap(),
+        hex::decode("115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a").unwrap()
+    );
+    assert_eq!(
+        poseidon_bn254(&[field_element(1)]).unwrap(),
+        hex::decode("29176100eaa962bdc1fe6c654d6a3c130e96a4d1168b33848b897dc502820133").unwrap()
+    );
+    // the modulus of the BN254 scalar field is not a valid field element.
+    let modulus =
+        hex::decode("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001").unwrap();
+    assert!(poseidon_bn254(&[modulus]).is_none());
+    assert!(poseidon_bn254(&[vec![1u8; 31]]).is_none());
+}
@@ -1,7 +1,16 @@
 pub mod bcs;
+pub mod bigint;
+pub mod codec;
+pub mod crypto;
 pub mod debug;
+pub mod gas_schedule;
 pub mod hash;
+pub mod math;
+pub mod string;
+pub mod sub_status;
 pub mod token;
+pub mod type_name;
+pub mod vector;
 // the following two modules are copied from diem-framework. As we don't want to add deps on diem.
 pub mod account;
 pub mod signature;
@@ -0,0 +1,83 @@
+use crate::gas_schedule::{starcoin_native_gas, StarcoinNativeCostIndex};
+use move_binary_format::errors::PartialVMResult;
+use move_vm_runtime::native_functions::NativeContext;
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+/// Rust implementation of Move's `native public fun checked_add_u128(a: u128, b: u128): (bool, u128);`
+/// Return `(true, a + b)`, or `(false, 0)` on overflow instead of aborting.
+pub fn native_checked_add_u128(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let b = pop_arg!(arguments, u128);
+    let a = pop_arg!(arguments, u128);
+
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::MATH_CHECKED_U128,
+        1,
+    );
+    let (ok, result) = checked_add_u128(a, b);
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::bool(ok), Value::u128(result)],
+    ))
+}
+
+/// Rust implementation of Move's `native public fun checked_mul_u128(a: u128, b: u128): (bool, u128);`
+/// Return `(true, a * b)`, or `(false, 0)` on overflow instead of aborting.
+pub fn native_checked_mul_u128(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let b = pop_arg!(arguments, u128);
+    let a = pop_arg!(arguments, u128);
+
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::MATH_CHECKED_U128,
+        1,
+    );
+    let (ok, result) = checked_mul_u128(a, b);
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::bool(ok), Value::u128(result)],
+    ))
+}
+
+fn checked_add_u128(a: u128, b: u128) -> (bool, u128) {
+    match a.checked_add(b) {
+        Some(result) => (true, result),
+        None => (false, 0),
+    }
+}
+
+fn checked_mul_u128(a: u128, b: u128) -> (bool, u128) {
+    match a.checked_mul(b) {
+        Some(result) => (true, result),
+        None => (false, 0),
+    }
+}
+
+#[test]
+fn test_checked_u128() {
+    assert_eq!(checked_add_u128(u128::MAX - 1, 1), (true, u128::MAX));
+    assert_eq!(checked_add_u128(u128::MAX, 0), (true, u128::MAX));
+    assert_eq!(checked_add_u128(u128::MAX, 1), (false, 0));
+    assert_eq!(checked_mul_u128(u128::MAX / 2, 2), (true, u128::MAX - 1));
+    assert_eq!(checked_mul_u128(u128::MAX / 2 + 1, 2), (false, 0));
+    assert_eq!(checked_mul_u128(u128::MAX, 1), (true, u128::MAX));
+    assert_eq!(checked_mul_u128(u128::MAX, 0), (true, 0));
+}
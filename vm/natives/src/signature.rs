@@ -1,7 +1,16 @@
 // Copyright (c) The Diem Core Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::gas_schedule::{starcoin_native_gas, StarcoinNativeCostIndex};
+use crate::sub_status::{
+    NFE_BLS12381_EMPTY_PUBLIC_KEYS, NFE_BLS12381_KEYS_MESSAGES_MISMATCH,
+    NFE_ED25519_BATCH_LENGTH_MISMATCH, NFE_ED25519_THRESHOLD_BITMAP_MISMATCH,
+    NFE_ED25519_THRESHOLD_SIGNATURES_MISMATCH, NFE_SCHNORR_INVALID_LENGTH,
+    NFE_SECP256K1_ECRECOVER_FAILURE,
+};
+use curve25519_dalek::edwards::CompressedEdwardsY;
 use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_schedule::GasAlgebra;
 use move_vm_runtime::native_functions::NativeContext;
 use move_vm_types::{
     gas_schedule::NativeCostIndex,
@@ -10,6 +19,7 @@ use move_vm_types::{
     pop_arg,
     values::Value,
 };
+use sha2::{Digest, Sha256};
 use smallvec::smallvec;
 use starcoin_crypto::{ed25519, traits::*};
 use std::{collections::VecDeque, convert::TryFrom};
@@ -72,3 +82,522 @@ pub fn native_ed25519_signature_verification(
         smallvec![Value::bool(verify_result)],
     ))
 }
+
+/// Rust implementation of Move's
+/// `native public fun ed25519_threshold_verify(bitmap: vector<u8>, public_keys: vector<u8>, message: vector<u8>, signatures: vector<u8>): u64;`
+/// `public_keys` is the concatenation of 32 bytes public keys, the i-th bit (from the most
+/// significant bit of the first byte) of `bitmap` marks whether the i-th key signed the message,
+/// and `signatures` is the concatenation of 64 bytes signatures in the order of the set bits.
+/// Return the count of valid signatures.
+pub fn native_ed25519_threshold_verify(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 4);
+
+    let signatures = pop_arg!(arguments, Vec<u8>);
+    let msg = pop_arg!(arguments, Vec<u8>);
+    let public_keys = pop_arg!(arguments, Vec<u8>);
+    let bitmap = pop_arg!(arguments, Vec<u8>);
+
+    let key_count = public_keys.len() / ed25519::ED25519_PUBLIC_KEY_LENGTH;
+    let signers = (0..key_count)
+        .filter(|i| {
+            bitmap
+                .get(i / 8)
+                .map_or(false, |b| b & (0b1000_0000 >> (i % 8)) != 0)
+        })
+        .collect::<Vec<_>>();
+    // every signer verifies the whole message, charge as `ed25519_verify` for each of them.
+    let cost = native_gas(
+        context.cost_table(),
+        NativeCostIndex::ED25519_VERIFY,
+        signers.len().saturating_mul(msg.len()),
+    );
+
+    let set_bits = bitmap
+        .iter()
+        .map(|b| b.count_ones() as usize)
+        .sum::<usize>();
+    if public_keys.len() % ed25519::ED25519_PUBLIC_KEY_LENGTH != 0
+        || bitmap.len() != (key_count + 7) / 8
+        || set_bits != signers.len()
+    {
+        return Ok(NativeResult::err(
+            cost,
+            NFE_ED25519_THRESHOLD_BITMAP_MISMATCH,
+        ));
+    }
+    if signatures.len() != signers.len() * ed25519::ED25519_SIGNATURE_LENGTH {
+        return Ok(NativeResult::err(
+            cost,
+            NFE_ED25519_THRESHOLD_SIGNATURES_MISMATCH,
+        ));
+    }
+
+    let valid_count = signers
+        .iter()
+        .zip(signatures.chunks(ed25519::ED25519_SIGNATURE_LENGTH))
+        .filter(|(i, signature)| {
+            let public_key = &public_keys[*i * ed25519::ED25519_PUBLIC_KEY_LENGTH
+                ..(*i + 1) * ed25519::ED25519_PUBLIC_KEY_LENGTH];
+            match (
+                ed25519::Ed25519Signature::try_from(*signature),
+                ed25519::Ed25519PublicKey::try_from(public_key),
+            ) {
+                (Ok(sig), Ok(pk)) => sig.verify_arbitrary_msg(msg.as_slice(), &pk).is_ok(),
+                _ => false,
+            }
+        })
+        .count();
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::u64(valid_count as u64)],
+    ))
+}
+
+/// Rust implementation of Move's
+/// `native public fun ed25519_batch_verify(public_keys: vector<vector<u8>>, messages: vector<vector<u8>>, signatures: vector<vector<u8>>): bool;`
+/// Return true if the i-th signature is a valid signature of the i-th message by the i-th key
+/// for every i.
+pub fn native_ed25519_batch_verify(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let signatures = pop_arg!(arguments, Vec<Value>)
+        .into_iter()
+        .map(|s| s.value_as::<Vec<u8>>())
+        .collect::<PartialVMResult<Vec<_>>>()?;
+    let messages = pop_arg!(arguments, Vec<Value>)
+        .into_iter()
+        .map(|m| m.value_as::<Vec<u8>>())
+        .collect::<PartialVMResult<Vec<_>>>()?;
+    let public_keys = pop_arg!(arguments, Vec<Value>)
+        .into_iter()
+        .map(|k| k.value_as::<Vec<u8>>())
+        .collect::<PartialVMResult<Vec<_>>>()?;
+
+    // charged per message byte as `ed25519_verify`, with at least one byte per message, but at
+    // a lower rate as the batch shares one multiscalar multiplication.
+    let message_bytes = messages
+        .iter()
+        .map(|m| std::cmp::max(1, m.len()))
+        .sum::<usize>();
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::ED25519_BATCH_VERIFY,
+        message_bytes,
+    );
+    if public_keys.len() != messages.len() || messages.len() != signatures.len() {
+        return Ok(NativeResult::err(cost, NFE_ED25519_BATCH_LENGTH_MISMATCH));
+    }
+
+    let verify_result = ed25519_batch_verify(&public_keys, &messages, &signatures);
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::bool(verify_result)],
+    ))
+}
+
+fn ed25519_batch_verify(
+    public_keys: &[Vec<u8>],
+    messages: &[Vec<u8>],
+    signatures: &[Vec<u8>],
+) -> bool {
+    let mut batch_keys = Vec::with_capacity(public_keys.len());
+    let mut batch_signatures = Vec::with_capacity(signatures.len());
+    for (pk, sig) in public_keys.iter().zip(signatures.iter()) {
+        // the checks of `ed25519_verify`: no key of small order, and a canonical `s`.
+        if ed25519::Ed25519PublicKey::try_from(pk.as_slice()).is_err()
+            || ed25519::Ed25519Signature::try_from(sig.as_slice()).is_err()
+        {
+            return false;
+        }
+        // The batch equation is not multiplied by the cofactor and is randomized, so a torsion
+        // component in a key or in the `R` of a signature makes the result depend on the random
+        // coefficients. Reject them to get the same result on every node, as verifying one by
+        // one. Honest signers never produce them.
+        if !is_torsion_free(pk.as_slice()) || !is_torsion_free(&sig[..32]) {
+            return false;
+        }
+        match (
+            ed25519_dalek::PublicKey::from_bytes(pk.as_slice()),
+            ed25519_dalek::Signature::try_from(sig.as_slice()),
+        ) {
+            (Ok(pk), Ok(sig)) => {
+                batch_keys.push(pk);
+                batch_signatures.push(sig);
+            }
+            _ => return false,
+        }
+    }
+    let messages = messages.iter().map(|m| m.as_slice()).collect::<Vec<_>>();
+    ed25519_dalek::verify_batch(&messages, &batch_signatures, &batch_keys).is_ok()
+}
+
+/// Whether the 32 bytes compressed edwards point is on the curve and has no torsion component.
+fn is_torsion_free(point: &[u8]) -> bool {
+    CompressedEdwardsY::from_slice(point)
+        .decompress()
+        .map_or(false, |point| point.is_torsion_free())
+}
+
+/// Rust implementation of Move's
+/// `native public fun secp256k1_ecrecover(hash: vector<u8>, recovery_id: u8, signature: vector<u8>): vector<u8>;`
+/// Return the 33 bytes compressed public key recovered from the signature.
+pub fn native_secp256k1_ecrecover(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let signature = pop_arg!(arguments, Vec<u8>);
+    let recovery_id = pop_arg!(arguments, u8);
+    let hash = pop_arg!(arguments, Vec<u8>);
+
+    // recovery is constant-time, so charge a fixed cost.
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::SECP256K1_ECRECOVER,
+        1,
+    );
+
+    match secp256k1_ecrecover(&hash, recovery_id, &signature) {
+        Some(public_key) => Ok(NativeResult::ok(
+            cost,
+            smallvec![Value::vector_u8(public_key)],
+        )),
+        None => Ok(NativeResult::err(cost, NFE_SECP256K1_ECRECOVER_FAILURE)),
+    }
+}
+
+fn secp256k1_ecrecover(hash: &[u8], recovery_id: u8, signature: &[u8]) -> Option<Vec<u8>> {
+    if hash.len() != 32 || signature.len() != 64 {
+        return None;
+    }
+    let mut msg = [0u8; 32];
+    msg.copy_from_slice(hash);
+    let msg = libsecp256k1::Message::parse(&msg);
+    let recovery_id = libsecp256k1::RecoveryId::parse(recovery_id).ok()?;
+    let signature = libsecp256k1::Signature::parse_standard_slice(signature).ok()?;
+    libsecp256k1::recover(&msg, &signature, &recovery_id)
+        .ok()
+        .map(|public_key| public_key.serialize_compressed().to_vec())
+}
+
+/// The field size p of secp256k1, big-endian.
+const SECP256K1_P: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xfc, 0x2f,
+];
+/// The group order n of secp256k1, big-endian.
+const SECP256K1_N: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// Rust implementation of Move's
+/// `native public fun schnorr_verify(public_key: vector<u8>, message: vector<u8>, signature: vector<u8>): bool;`
+/// Verify a BIP-340 schnorr signature over secp256k1, `public_key` is the 32 bytes x-only key
+/// and `signature` is the 64 bytes `r || s`.
+pub fn native_schnorr_verify(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let signature = pop_arg!(arguments, Vec<u8>);
+    let msg = pop_arg!(arguments, Vec<u8>);
+    let public_key = pop_arg!(arguments, Vec<u8>);
+
+    // the message only goes into one hash, the cost is dominated by the scalar multiplications.
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::SCHNORR_VERIFY,
+        1,
+    );
+    if public_key.len() != 32 || signature.len() != 64 {
+        return Ok(NativeResult::err(cost, NFE_SCHNORR_INVALID_LENGTH));
+    }
+
+    let verify_result = schnorr_verify(&public_key, &msg, &signature);
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::bool(verify_result)],
+    ))
+}
+
+fn schnorr_verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    use libsecp256k1::curve::{Affine, Field, Jacobian, Scalar};
+
+    let (r, s) = signature.split_at(32);
+    if public_key >= &SECP256K1_P[..] || r >= &SECP256K1_P[..] || s >= &SECP256K1_N[..] {
+        return false;
+    }
+    let mut x = Field::default();
+    let _ = x.set_b32(&to_array32(public_key));
+    // lift_x: the point with the even y.
+    let mut p = Affine::default();
+    if !p.set_xo_var(&x, false) {
+        return false;
+    }
+    let mut pj = Jacobian::default();
+    pj.set_ge(&p);
+
+    let mut e = Scalar::default();
+    let _ = e.set_b32(&bip340_challenge(r, public_key, message));
+    let mut s_scalar = Scalar::default();
+    let _ = s_scalar.set_b32(&to_array32(s));
+
+    // R = s * G - e * P
+    let mut rj = Jacobian::default();
+    libsecp256k1::ECMULT_CONTEXT.ecmult(&mut rj, &pj, &-e, &s_scalar);
+    if rj.is_infinity() {
+        return false;
+    }
+    let mut ra = Affine::default();
+    ra.set_gej_var(&rj);
+    ra.x.normalize_var();
+    ra.y.normalize_var();
+    !ra.y.is_odd() && ra.x.b32()[..] == *r
+}
+
+/// `tagged_hash("BIP0340/challenge", r || public_key || message)`
+fn bip340_challenge(r: &[u8], public_key: &[u8], message: &[u8]) -> [u8; 32] {
+    let tag = Sha256::digest(b"BIP0340/challenge");
+    let mut hasher = Sha256::new();
+    hasher.update(&tag);
+    hasher.update(&tag);
+    hasher.update(r);
+    hasher.update(public_key);
+    hasher.update(message);
+    to_array32(&hasher.finalize())
+}
+
+fn to_array32(bytes: &[u8]) -> [u8; 32] {
+    let mut array = [0u8; 32];
+    array.copy_from_slice(bytes);
+    array
+}
+
+/// The domain separation tag of BLS signatures in proof-of-possession scheme, same as Eth2.
+const BLS12381_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Rust implementation of Move's
+/// `native public fun bls12381_aggregate_verify(public_keys: vector<vector<u8>>, messages: vector<vector<u8>>, signature: vector<u8>): bool;`
+/// The i-th message is signed by the i-th public key.
+pub fn native_bls12381_aggregate_verify(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let signature = pop_arg!(arguments, Vec<u8>);
+    let messages = pop_arg!(arguments, Vec<Value>)
+        .into_iter()
+        .map(|m| m.value_as::<Vec<u8>>())
+        .collect::<PartialVMResult<Vec<_>>>()?;
+    let public_keys = pop_arg!(arguments, Vec<Value>)
+        .into_iter()
+        .map(|k| k.value_as::<Vec<u8>>())
+        .collect::<PartialVMResult<Vec<_>>>()?;
+
+    let message_bytes = messages.iter().map(|m| m.len()).sum();
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::BLS12381_AGGREGATE_VERIFY,
+        public_keys.len(),
+    )
+    .add(starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::BLS12381_AGGREGATE_VERIFY_PER_BYTE,
+        message_bytes,
+    ));
+    if public_keys.is_empty() {
+        return Ok(NativeResult::err(cost, NFE_BLS12381_EMPTY_PUBLIC_KEYS));
+    }
+    if public_keys.len() != messages.len() {
+        return Ok(NativeResult::err(cost, NFE_BLS12381_KEYS_MESSAGES_MISMATCH));
+    }
+
+    let verify_result = bls12381_aggregate_verify(&public_keys, &messages, &signature);
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::bool(verify_result)],
+    ))
+}
+
+fn bls12381_aggregate_verify(
+    public_keys: &[Vec<u8>],
+    messages: &[Vec<u8>],
+    signature: &[u8],
+) -> bool {
+    let public_keys = match public_keys
+        .iter()
+        .map(|k| blst::min_pk::PublicKey::from_bytes(k))
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(public_keys) => public_keys,
+        Err(_) => return false,
+    };
+    let signature = match blst::min_pk::Signature::from_bytes(signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    let public_keys = public_keys.iter().collect::<Vec<_>>();
+    let messages = messages.iter().map(|m| m.as_slice()).collect::<Vec<_>>();
+    signature.aggregate_verify(true, &messages, BLS12381_DST, &public_keys, true)
+        == blst::BLST_ERROR::BLST_SUCCESS
+}
+
+#[test]
+fn test_secp256k1_ecrecover() {
+    let secret_key = libsecp256k1::SecretKey::parse(&[1u8; 32]).unwrap();
+    let public_key = libsecp256k1::PublicKey::from_secret_key(&secret_key);
+    let hash = [2u8; 32];
+    let (signature, recovery_id) =
+        libsecp256k1::sign(&libsecp256k1::Message::parse(&hash), &secret_key);
+
+    let recovered =
+        secp256k1_ecrecover(&hash, recovery_id.serialize(), &signature.serialize()).unwrap();
+    assert_eq!(recovered, public_key.serialize_compressed().to_vec());
+
+    assert!(
+        secp256k1_ecrecover(&hash[1..], recovery_id.serialize(), &signature.serialize()).is_none()
+    );
+    assert!(secp256k1_ecrecover(&hash, 4, &signature.serialize()).is_none());
+    assert!(secp256k1_ecrecover(&hash, recovery_id.serialize(), &[0u8; 63]).is_none());
+}
+
+#[test]
+fn test_schnorr_verify() {
+    // test vector 0 and the public key of test vector 5 of BIP-340.
+    let public_key =
+        hex::decode("F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9").unwrap();
+    let message = [0u8; 32];
+    let signature = hex::decode("E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0").unwrap();
+    assert!(schnorr_verify(&public_key, &message, &signature));
+    assert!(!schnorr_verify(&public_key, &[1u8; 32], &signature));
+    let mut tampered = signature.clone();
+    tampered[63] ^= 1;
+    assert!(!schnorr_verify(&public_key, &message, &tampered));
+    let not_on_curve =
+        hex::decode("EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34").unwrap();
+    assert!(!schnorr_verify(&not_on_curve, &message, &signature));
+    let mut s_overflow = signature.clone();
+    s_overflow[32..].copy_from_slice(&SECP256K1_N);
+    assert!(!schnorr_verify(&public_key, &message, &s_overflow));
+
+    // messages are not limited to 32 bytes.
+    let public_key =
+        hex::decode("DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659").unwrap();
+    let signature = hex::decode("C3C31A623DD9EF6FAE4D06E709D4EE140B73FFE384BE0796F975985D42D55AF7E0EFB093753171579D1E6C81B607D8DCFD6E16BDEACFD1120F9032D1BA3A25B6").unwrap();
+    assert!(schnorr_verify(&public_key, b"starcoin", &signature));
+    assert!(!schnorr_verify(&public_key, b"starcoim", &signature));
+}
+
+#[test]
+fn test_bls12381_aggregate_verify() {
+    let secret_keys = (1u8..=3)
+        .map(|i| blst::min_pk::SecretKey::key_gen(&[i; 32], &[]).unwrap())
+        .collect::<Vec<_>>();
+    let messages = vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec()];
+    let signatures = secret_keys
+        .iter()
+        .zip(messages.iter())
+        .map(|(sk, msg)| sk.sign(msg, BLS12381_DST, &[]))
+        .collect::<Vec<_>>();
+    let signature =
+        blst::min_pk::AggregateSignature::aggregate(&signatures.iter().collect::<Vec<_>>(), true)
+            .unwrap()
+            .to_signature()
+            .to_bytes();
+    let public_keys = secret_keys
+        .iter()
+        .map(|sk| sk.sk_to_pk().to_bytes().to_vec())
+        .collect::<Vec<_>>();
+
+    assert!(bls12381_aggregate_verify(
+        &public_keys,
+        &messages,
+        &signature
+    ));
+    let mut tampered = messages.clone();
+    tampered.swap(0, 1);
+    assert!(!bls12381_aggregate_verify(
+        &public_keys,
+        &tampered,
+        &signature
+    ));
+    assert!(!bls12381_aggregate_verify(
+        &public_keys,
+        &messages,
+        &signature[1..]
+    ));
+}
+
+#[test]
+fn test_ed25519_batch_verify() {
+    use ed25519_dalek::Signer;
+
+    let keypairs = (1u8..=3)
+        .map(|i| {
+            let secret = ed25519_dalek::SecretKey::from_bytes(&[i; 32]).unwrap();
+            let public = ed25519_dalek::PublicKey::from(&secret);
+            ed25519_dalek::Keypair { secret, public }
+        })
+        .collect::<Vec<_>>();
+    let messages = vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec()];
+    let signatures = keypairs
+        .iter()
+        .zip(messages.iter())
+        .map(|(kp, msg)| kp.sign(msg).to_bytes().to_vec())
+        .collect::<Vec<_>>();
+    let public_keys = keypairs
+        .iter()
+        .map(|kp| kp.public.to_bytes().to_vec())
+        .collect::<Vec<_>>();
+
+    assert!(ed25519_batch_verify(&public_keys, &messages, &signatures));
+    let mut tampered = messages.clone();
+    tampered.swap(0, 1);
+    assert!(!ed25519_batch_verify(&public_keys, &tampered, &signatures));
+    let mut truncated = signatures.clone();
+    truncated[2].pop();
+    assert!(!ed25519_batch_verify(&public_keys, &messages, &truncated));
+    // a signature with `R` of order 2 is rejected before the batch equation.
+    let mut small_order_r = signatures.clone();
+    small_order_r[0][..32].copy_from_slice(&ED25519_ORDER_2_POINT);
+    assert!(!ed25519_batch_verify(
+        &public_keys,
+        &messages,
+        &small_order_r
+    ));
+}
+
+/// The compressed point (0, -1) of order 2.
+#[cfg(test)]
+const ED25519_ORDER_2_POINT: [u8; 32] = [
+    0xec, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f,
+];
+
+#[test]
+fn test_is_torsion_free() {
+    let public_key =
+        ed25519_dalek::PublicKey::from(&ed25519_dalek::SecretKey::from_bytes(&[1u8; 32]).unwrap());
+    assert!(is_torsion_free(public_key.as_bytes()));
+    assert!(!is_torsion_free(&ED25519_ORDER_2_POINT));
+}
@@ -0,0 +1,102 @@
+use crate::gas_schedule::{starcoin_native_gas, StarcoinNativeCostIndex};
+use move_binary_format::errors::PartialVMResult;
+use move_vm_runtime::native_functions::NativeContext;
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+/// Rust implementation of Move's `native public fun is_utf8(bytes: vector<u8>): bool;`
+pub fn native_is_utf8(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let bytes = pop_arg!(arguments, Vec<u8>);
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::STRING_IS_UTF8,
+        bytes.len(),
+    );
+    let valid = std::str::from_utf8(bytes.as_slice()).is_ok();
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(valid)]))
+}
+
+/// Rust implementation of Move's `native public fun internal_char_boundary(bytes: vector<u8>, i: u64): bool;`
+/// Return whether `i` is a char boundary of the UTF-8 `bytes`, `bytes` should be valid UTF-8.
+pub fn native_internal_char_boundary(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let i = pop_arg!(arguments, u64);
+    let bytes = pop_arg!(arguments, Vec<u8>);
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::STRING_INTERNAL_CHAR_BOUNDARY,
+        bytes.len(),
+    );
+    let is_boundary = std::str::from_utf8(bytes.as_slice())
+        .map(|s| s.is_char_boundary(i as usize))
+        .unwrap_or(false);
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(is_boundary)]))
+}
+
+/// Rust implementation of Move's `native public fun parse_u128(bytes: vector<u8>): (bool, u128);`
+/// Parse decimal digits to u128, return `(false, 0)` on empty input, non-digit bytes or overflow.
+pub fn native_parse_u128(
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let bytes = pop_arg!(arguments, Vec<u8>);
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::STRING_PARSE_U128,
+        bytes.len(),
+    );
+    let (ok, value) = match parse_u128(bytes.as_slice()) {
+        Some(value) => (true, value),
+        None => (false, 0),
+    };
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::bool(ok), Value::u128(value)],
+    ))
+}
+
+fn parse_u128(bytes: &[u8]) -> Option<u128> {
+    if bytes.is_empty() {
+        return None;
+    }
+    bytes.iter().try_fold(0u128, |acc, b| {
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        acc.checked_mul(10)?.checked_add((b - b'0') as u128)
+    })
+}
+
+#[test]
+fn test_parse_u128() {
+    assert_eq!(parse_u128(b"0"), Some(0));
+    assert_eq!(parse_u128(b"000123"), Some(123));
+    assert_eq!(
+        parse_u128(b"340282366920938463463374607431768211455"),
+        Some(u128::max_value())
+    );
+    assert_eq!(parse_u128(b"340282366920938463463374607431768211456"), None);
+    assert_eq!(parse_u128(b""), None);
+    assert_eq!(parse_u128(b"+1"), None);
+    assert_eq!(parse_u128(b"12a"), None);
+}
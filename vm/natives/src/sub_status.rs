@@ -0,0 +1,58 @@
+//! Native function error sub-status codes of starcoin natives.
+//! Continues the `NFE_*` codes in `move_core_types::vm_status::sub_status`.
+
+/// `BCS::to_address` got key bytes whose length is not the address length.
+pub use move_core_types::vm_status::sub_status::NFE_BCS_TO_ADDRESS_FAILURE;
+
+/// secp256k1 ecrecover got malformed input: hash, recovery id or signature.
+pub const NFE_SECP256K1_ECRECOVER_FAILURE: u64 = 0x1D0;
+/// bls12381 aggregate verify got an empty public key vector.
+pub const NFE_BLS12381_EMPTY_PUBLIC_KEYS: u64 = 0x1D1;
+/// bls12381 aggregate verify got different count of public keys and messages.
+pub const NFE_BLS12381_KEYS_MESSAGES_MISMATCH: u64 = 0x1D2;
+/// ed25519 threshold verify got a bitmap which does not match the public keys.
+pub const NFE_ED25519_THRESHOLD_BITMAP_MISMATCH: u64 = 0x1D3;
+/// ed25519 threshold verify got signatures which do not match the bitmap.
+pub const NFE_ED25519_THRESHOLD_SIGNATURES_MISMATCH: u64 = 0x1D4;
+/// base58 decode got invalid characters.
+pub const NFE_BASE58_DECODE_FAILURE: u64 = 0x1D5;
+/// merkle root got an empty leaf vector.
+pub const NFE_MERKLE_ROOT_EMPTY_LEAVES: u64 = 0x1D6;
+/// siphash got a key whose length is not 16 bytes.
+pub const NFE_SIPHASH_INVALID_KEY_LENGTH: u64 = 0x1D7;
+/// merkle proof verify got a proof which is not a sequence of 33 bytes `(is_left, sibling)` items.
+pub const NFE_MERKLE_PROOF_MALFORMED: u64 = 0x1D8;
+/// bigint modexp got a zero modulus.
+pub const NFE_BIGINT_MODEXP_ZERO_MODULUS: u64 = 0x1D9;
+/// ed25519 batch verify got public keys, messages and signatures of different lengths.
+pub const NFE_ED25519_BATCH_LENGTH_MISMATCH: u64 = 0x1DA;
+/// hex decode got odd length or non-hex characters.
+pub const NFE_HEX_DECODE_FAILURE: u64 = 0x1DB;
+/// aes gcm got a key which is not 16 or 32 bytes.
+pub const NFE_AES_GCM_INVALID_KEY_LENGTH: u64 = 0x1DC;
+/// poseidon got an input which is not a 32 bytes big-endian BN254 scalar field element.
+pub const NFE_POSEIDON_INVALID_FIELD_ELEMENT: u64 = 0x1DD;
+/// poseidon got no input or more inputs than supported.
+pub const NFE_POSEIDON_INVALID_INPUT_COUNT: u64 = 0x1DE;
+/// bn254 got a G1 point which is not a compressed point on the curve.
+pub const NFE_BN254_INVALID_G1_POINT: u64 = 0x1DF;
+/// bcs got bytes which are not the encoding of the expected type.
+pub const NFE_BCS_FROM_BYTES_FAILURE: u64 = 0x1E0;
+/// groth16 got a verifying key which is malformed or has points not on the curve.
+pub const NFE_GROTH16_INVALID_VERIFYING_KEY: u64 = 0x1E1;
+/// vector got an element type which is not supported by the native.
+pub const NFE_VECTOR_UNSUPPORTED_ELEMENT_TYPE: u64 = 0x1E2;
+/// schnorr verify got a public key or signature of wrong length.
+pub const NFE_SCHNORR_INVALID_LENGTH: u64 = 0x1E3;
+/// vector got operands of different lengths.
+pub const NFE_VECTOR_LENGTH_MISMATCH: u64 = 0x1E4;
+/// base58 got an input longer than `MAX_BASE58_INPUT_LENGTH`.
+pub const NFE_BASE58_INPUT_TOO_LONG: u64 = 0x1E5;
+/// merkle root got a leaf which is not a 32 bytes hash.
+pub const NFE_MERKLE_ROOT_INVALID_LEAF_LENGTH: u64 = 0x1E6;
+/// bigint modexp got a base, exponent or modulus longer than `MAX_MODEXP_INPUT_LENGTH`.
+pub const NFE_BIGINT_MODEXP_INPUT_TOO_LONG: u64 = 0x1E7;
+/// groth16 got a verifying key with more public inputs than `MAX_GROTH16_PUBLIC_INPUTS`.
+pub const NFE_GROTH16_TOO_MANY_PUBLIC_INPUTS: u64 = 0x1E8;
+/// merkle proof got a leaf which is not a 32 bytes hash.
+pub const NFE_MERKLE_PROOF_INVALID_LEAF_LENGTH: u64 = 0x1E9;
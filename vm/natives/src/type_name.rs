@@ -0,0 +1,67 @@
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::language_storage::TypeTag;
+use move_vm_runtime::native_functions::NativeContext;
+use move_vm_types::{
+    gas_schedule::NativeCostIndex,
+    loaded_data::runtime_types::Type,
+    natives::function::{native_gas, NativeResult},
+    values::Value,
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+/// Rust implementation of Move's `native public fun name_of<T>(): vector<u8>;`
+/// Return the type tag string of `T`, such as `u64` or
+/// `0x00000000000000000000000000000001::STC::STC`.
+pub fn native_type_name_of(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(arguments.is_empty());
+
+    let cost = native_gas(context.cost_table(), NativeCostIndex::TOKEN_NAME_OF, 1);
+    let type_tag = context.type_to_type_tag(&ty_args[0])?;
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(type_name(&type_tag))],
+    ))
+}
+
+fn type_name(type_tag: &TypeTag) -> Vec<u8> {
+    type_tag.to_string().into_bytes()
+}
+
+#[test]
+fn test_type_name() {
+    use move_core_types::identifier::Identifier;
+    use move_core_types::language_storage::{StructTag, CORE_CODE_ADDRESS};
+    let stc = StructTag {
+        address: CORE_CODE_ADDRESS,
+        module: Identifier::new("STC").unwrap(),
+        name: Identifier::new("STC").unwrap(),
+        type_params: vec![],
+    };
+    let token = StructTag {
+        address: CORE_CODE_ADDRESS,
+        module: Identifier::new("Token").unwrap(),
+        name: Identifier::new("Token").unwrap(),
+        type_params: vec![TypeTag::Struct(stc.clone())],
+    };
+    let cases = vec![
+        (TypeTag::U64, "u64"),
+        (TypeTag::Vector(Box::new(TypeTag::U8)), "vector<u8>"),
+        (
+            TypeTag::Struct(stc),
+            "0x00000000000000000000000000000001::STC::STC",
+        ),
+        (
+            TypeTag::Struct(token),
+            "0x00000000000000000000000000000001::Token::Token<0x00000000000000000000000000000001::STC::STC>",
+        ),
+    ];
+    for (type_tag, expected) in cases {
+        assert_eq!(type_name(&type_tag), expected.as_bytes());
+    }
+}
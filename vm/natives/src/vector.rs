@@ -0,0 +1,412 @@
+use crate::gas_schedule::{starcoin_native_gas, StarcoinNativeCostIndex};
+use crate::sub_status::{NFE_VECTOR_LENGTH_MISMATCH, NFE_VECTOR_UNSUPPORTED_ELEMENT_TYPE};
+use move_binary_format::errors::{PartialVMError, PartialVMResult};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::vm_status::StatusCode;
+use move_vm_runtime::native_functions::NativeContext;
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Reference, Value, VectorRef},
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+/// Same as the `EINDEX_OUT_OF_BOUNDS` abort code in Vector.move.
+pub const EINDEX_OUT_OF_BOUNDS: u64 = 0;
+
+/// Rust implementation of Move's `native public fun append<Element>(lhs: &mut vector<Element>, other: vector<Element>);`
+pub fn native_append(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 2);
+
+    let other = args.pop_back().unwrap();
+    let lhs = pop_arg!(args, VectorRef);
+
+    let elements = unpack_vector(other, &ty_args[0])?;
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::VECTOR_APPEND,
+        elements.len(),
+    );
+    let result = elements
+        .into_iter()
+        .try_for_each(|e| lhs.push_back(e, &ty_args[0]));
+    NativeResult::map_partial_vm_result_empty(cost, result)
+}
+
+/// Rust implementation of Move's `native public fun reverse<Element>(v: &mut vector<Element>);`
+pub fn native_reverse(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 1);
+
+    let r = pop_arg!(args, VectorRef);
+    let len = r.len(&ty_args[0])?.value_as::<u64>()? as usize;
+
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::VECTOR_REVERSE,
+        len / 2,
+    );
+    let result = (0..len / 2).try_for_each(|i| r.swap(i, len - 1 - i, &ty_args[0]));
+    NativeResult::map_partial_vm_result_empty(cost, result)
+}
+
+/// Rust implementation of Move's `native public fun index_of<Element>(v: &vector<Element>, e: &Element): (bool, u64);`
+/// Return `(false, 0)` if `e` is not in `v`.
+pub fn native_index_of(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 2);
+
+    let e = args.pop_back().unwrap();
+    let r = pop_arg!(args, VectorRef);
+
+    let (found, scanned) = index_of(&r, &e, &ty_args[0])?;
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::VECTOR_INDEX_OF,
+        scanned,
+    );
+    let index = if found { scanned - 1 } else { 0 };
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::bool(found), Value::u64(index as u64)],
+    ))
+}
+
+/// Rust implementation of Move's `native public fun contains<Element>(v: &vector<Element>, e: &Element): bool;`
+pub fn native_contains(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 2);
+
+    let e = args.pop_back().unwrap();
+    let r = pop_arg!(args, VectorRef);
+
+    let (found, scanned) = index_of(&r, &e, &ty_args[0])?;
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::VECTOR_CONTAINS,
+        scanned,
+    );
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(found)]))
+}
+
+/// Rust implementation of Move's `native public fun remove<Element>(v: &mut vector<Element>, i: u64): Element;`
+/// The elements after `i` are shifted, abort with `EINDEX_OUT_OF_BOUNDS` if `i` is out of bounds.
+pub fn native_remove(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 2);
+
+    let i = pop_arg!(args, u64) as usize;
+    let r = pop_arg!(args, VectorRef);
+    let len = r.len(&ty_args[0])?.value_as::<u64>()? as usize;
+
+    let shifts = len.saturating_sub(i.saturating_add(1));
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::VECTOR_REMOVE,
+        shifts,
+    );
+    if i >= len {
+        return Ok(NativeResult::err(cost, EINDEX_OUT_OF_BOUNDS));
+    }
+    (i..len - 1).try_for_each(|j| r.swap(j, j + 1, &ty_args[0]))?;
+    let e = r.pop(&ty_args[0])?;
+    Ok(NativeResult::ok(cost, smallvec![e]))
+}
+
+/// Rust implementation of Move's `native public fun fill<Element: copy + drop>(v: &mut vector<Element>, e: Element);`
+/// Set every element of `v` to a copy of `e`, the length of `v` is not changed.
+pub fn native_fill(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 2);
+
+    let e = args.pop_back().unwrap();
+    let r = pop_arg!(args, VectorRef);
+    let len = r.len(&ty_args[0])?.value_as::<u64>()? as usize;
+
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::VECTOR_FILL,
+        len,
+    );
+    let result = (0..len).try_for_each(|i| {
+        r.borrow_elem(i, &ty_args[0])?
+            .value_as::<Reference>()?
+            .write_ref(e.copy_value()?)
+    });
+    NativeResult::map_partial_vm_result_empty(cost, result)
+}
+
+/// Rust implementation of Move's `native public fun split_off<Element>(v: &mut vector<Element>, at: u64): vector<Element>;`
+/// Truncate `v` to `at` elements and return the removed tail, abort with `EINDEX_OUT_OF_BOUNDS`
+/// if `at` is greater than the length of `v`.
+/// Only vectors of u8, u64, u128, bool and address are supported, as a new vector of other
+/// element types can not be created from a native, abort with
+/// `NFE_VECTOR_UNSUPPORTED_ELEMENT_TYPE` for them.
+pub fn native_split_off(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 2);
+
+    let at = pop_arg!(args, u64) as usize;
+    let r = pop_arg!(args, VectorRef);
+    let len = r.len(&ty_args[0])?.value_as::<u64>()? as usize;
+
+    let moved = len.saturating_sub(at);
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::VECTOR_SPLIT_OFF,
+        moved,
+    );
+    if at > len {
+        return Ok(NativeResult::err(cost, EINDEX_OUT_OF_BOUNDS));
+    }
+    if !is_primitive_type(&ty_args[0]) {
+        return Ok(NativeResult::err(cost, NFE_VECTOR_UNSUPPORTED_ELEMENT_TYPE));
+    }
+    let mut tail = (0..moved)
+        .map(|_| r.pop(&ty_args[0]))
+        .collect::<PartialVMResult<Vec<_>>>()?;
+    tail.reverse();
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![pack_vector(tail, &ty_args[0])?],
+    ))
+}
+
+/// Rust implementation of Move's `native public fun constant_time_eq(a: vector<u8>, b: vector<u8>): bool;`
+/// For comparing secrets of the same length, the time only depends on the length.
+/// Different lengths return false immediately.
+pub fn native_constant_time_eq(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let b = pop_arg!(args, Vec<u8>);
+    let a = pop_arg!(args, Vec<u8>);
+
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::VECTOR_CONSTANT_TIME_EQ,
+        std::cmp::max(a.len(), b.len()),
+    );
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::bool(constant_time_eq(a.as_slice(), b.as_slice()))],
+    ))
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter()
+        .zip(b.iter())
+        .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+        == 0
+}
+
+/// Rust implementation of Move's `native public fun conditional_select(choice: bool, a: vector<u8>, b: vector<u8>): vector<u8>;`
+/// Return a copy of `a` if `choice` is true, otherwise `b`, abort with
+/// `NFE_VECTOR_LENGTH_MISMATCH` if the lengths differ.
+/// Every byte of both vectors is combined with a mask instead of branching on `choice`, so
+/// neither the time nor the gas depends on `choice`.
+pub fn native_conditional_select(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let b = pop_arg!(args, Vec<u8>);
+    let a = pop_arg!(args, Vec<u8>);
+    let choice = pop_arg!(args, bool);
+
+    let cost = starcoin_native_gas(
+        context.cost_table(),
+        StarcoinNativeCostIndex::VECTOR_CONDITIONAL_SELECT,
+        std::cmp::max(a.len(), b.len()),
+    );
+    if a.len() != b.len() {
+        return Ok(NativeResult::err(cost, NFE_VECTOR_LENGTH_MISMATCH));
+    }
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(conditional_select(
+            choice,
+            a.as_slice(),
+            b.as_slice()
+        ))],
+    ))
+}
+
+fn conditional_select(choice: bool, a: &[u8], b: &[u8]) -> Vec<u8> {
+    debug_assert!(a.len() == b.len());
+    // 0xff if choice is true, otherwise 0x00.
+    let mask = 0u8.wrapping_sub(choice as u8);
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (x & mask) | (y & !mask))
+        .collect()
+}
+
+/// Scan `r` for `e`, return whether it is found and the count of scanned elements.
+fn index_of(r: &VectorRef, e: &Value, type_param: &Type) -> PartialVMResult<(bool, usize)> {
+    let len = r.len(type_param)?.value_as::<u64>()? as usize;
+    for i in 0..len {
+        if r.borrow_elem(i, type_param)?.equals(e)? {
+            return Ok((true, i + 1));
+        }
+    }
+    Ok((false, len))
+}
+
+fn is_primitive_type(type_param: &Type) -> bool {
+    matches!(
+        type_param,
+        Type::U8 | Type::U64 | Type::U128 | Type::Bool | Type::Address
+    )
+}
+
+/// Pack the elements into a new vector value, fail with an invariant violation if the element
+/// type is not primitive, the callers must check it with `is_primitive_type` first.
+fn pack_vector(elements: Vec<Value>, type_param: &Type) -> PartialVMResult<Value> {
+    Ok(match type_param {
+        Type::U8 => Value::vector_u8(
+            elements
+                .into_iter()
+                .map(|e| e.value_as::<u8>())
+                .collect::<PartialVMResult<Vec<_>>>()?,
+        ),
+        Type::U64 => Value::vector_u64(
+            elements
+                .into_iter()
+                .map(|e| e.value_as::<u64>())
+                .collect::<PartialVMResult<Vec<_>>>()?,
+        ),
+        Type::U128 => Value::vector_u128(
+            elements
+                .into_iter()
+                .map(|e| e.value_as::<u128>())
+                .collect::<PartialVMResult<Vec<_>>>()?,
+        ),
+        Type::Bool => Value::vector_bool(
+            elements
+                .into_iter()
+                .map(|e| e.value_as::<bool>())
+                .collect::<PartialVMResult<Vec<_>>>()?,
+        ),
+        Type::Address => Value::vector_address(
+            elements
+                .into_iter()
+                .map(|e| e.value_as::<AccountAddress>())
+                .collect::<PartialVMResult<Vec<_>>>()?,
+        ),
+        _ => {
+            return Err(
+                PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                    .with_message("only vectors of primitive types can be packed".to_string()),
+            )
+        }
+    })
+}
+
+/// Move all elements out of an owned vector value, in order.
+fn unpack_vector(v: Value, type_param: &Type) -> PartialVMResult<Vec<Value>> {
+    Ok(match type_param {
+        Type::U8 => v
+            .value_as::<Vec<u8>>()?
+            .into_iter()
+            .map(Value::u8)
+            .collect(),
+        Type::U64 => v
+            .value_as::<Vec<u64>>()?
+            .into_iter()
+            .map(Value::u64)
+            .collect(),
+        Type::U128 => v
+            .value_as::<Vec<u128>>()?
+            .into_iter()
+            .map(Value::u128)
+            .collect(),
+        Type::Bool => v
+            .value_as::<Vec<bool>>()?
+            .into_iter()
+            .map(Value::bool)
+            .collect(),
+        Type::Address => v
+            .value_as::<Vec<AccountAddress>>()?
+            .into_iter()
+            .map(Value::address)
+            .collect(),
+        _ => v.value_as::<Vec<Value>>()?,
+    })
+}
+
+#[test]
+fn test_constant_time_eq() {
+    assert!(constant_time_eq(b"", b""));
+    assert!(constant_time_eq(b"secret", b"secret"));
+    assert!(!constant_time_eq(b"secret", b"secreT"));
+    assert!(!constant_time_eq(b"secret", b"secret1"));
+}
+
+#[test]
+fn test_conditional_select() {
+    assert_eq!(conditional_select(true, b"secret", b"public"), b"secret");
+    assert_eq!(conditional_select(false, b"secret", b"public"), b"public");
+    assert_eq!(
+        conditional_select(true, &[0x00, 0xff], &[0xff, 0x00]),
+        vec![0x00, 0xff]
+    );
+    assert!(conditional_select(false, b"", b"").is_empty());
+}
+
+#[test]
+fn test_split_off_pack_vector() {
+    let v = (0..100u64).collect::<Vec<_>>();
+    for at in vec![0, 1, 50, 99, 100] {
+        let mut head = v.iter().copied().map(Value::u64).collect::<Vec<_>>();
+        let tail = head.split_off(at);
+        let tail = unpack_vector(pack_vector(tail, &Type::U64).unwrap(), &Type::U64).unwrap();
+        assert_eq!(head.len(), at);
+        assert_eq!(tail.len(), v.len() - at);
+        for (e, expected) in tail.into_iter().zip(v[at..].iter()) {
+            assert_eq!(e.value_as::<u64>().unwrap(), *expected);
+        }
+    }
+    assert!(!is_primitive_type(&Type::Vector(Box::new(Type::U8))));
+}
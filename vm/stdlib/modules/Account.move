@@ -253,6 +253,16 @@ module Account {
 
     native fun create_signer(addr: address): signer;
 
+    /// Return the address of the resource account created by `source` with `seed`, which is the
+    /// last 16 bytes of `sha3_256(source | seed | 0xFF)`. The trailing `0xFF` scheme keeps it away
+    /// from the addresses derived from authentication keys.
+    native public fun create_resource_address(source: address, seed: vector<u8>): address;
+
+    spec create_resource_address {
+        pragma opaque = true;
+        aborts_if false;
+    }
+
     public(script) fun create_account_with_initial_amount<TokenType: store>(account: signer, fresh_address: address, _auth_key: vector<u8>, initial_amount: u128)
     acquires Account, Balance, AutoAcceptToken {
          create_account_with_initial_amount_v2<TokenType>(account, fresh_address, initial_amount)
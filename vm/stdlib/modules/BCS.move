@@ -12,11 +12,22 @@ module BCS {
 
     /// Return the address of key bytes
     native public fun to_address(key_bytes: vector<u8>): address;
+
+    /// Return the value of type `MoveValue` decoded from its BCS representation, the inverse of `to_bytes`.
+    /// Aborts if `bytes` is not a complete BCS encoding of `MoveValue`, or `MoveValue` contains a `signer`.
+    /// `MoveValue` must have `copy` and `drop`, so a resource can not be forged from bytes.
+    native public fun from_bytes<MoveValue: copy + drop>(bytes: vector<u8>): MoveValue;
     // ------------------------------------------------------------------------
     // Specification
     // ------------------------------------------------------------------------
 
 
     spec native fun serialize<MoveValue>(v: &MoveValue): vector<u8>;
+
+    spec from_bytes {
+        pragma opaque;
+        aborts_if [abstract] !(exists v: MoveValue: serialize(v) == bytes);
+        ensures [abstract] serialize(result) == bytes;
+    }
 }
 }
@@ -0,0 +1,26 @@
+address 0x1 {
+/// The module provide arithmetic on big unsigned integers, which are big-endian `vector<u8>`.
+module BigInt {
+    spec module {
+        pragma verify;
+        pragma aborts_if_is_strict;
+    }
+
+    /// The max byte length of each of the base, exponent and modulus of `modexp`.
+    const MAX_MODEXP_INPUT_LENGTH: u64 = 512;
+
+    /// Return `base ^ exponent % modulus`, all the numbers are big-endian unsigned integers.
+    /// The gas is charged as EIP-2565.
+    /// Aborts with 0x1D9 if `modulus` is zero, and with 0x1E7 if any number is longer than
+    /// `MAX_MODEXP_INPUT_LENGTH` bytes.
+    native public fun modexp(base: vector<u8>, exponent: vector<u8>, modulus: vector<u8>): vector<u8>;
+
+    spec modexp {
+        pragma opaque = true;
+        aborts_if len(base) > MAX_MODEXP_INPUT_LENGTH;
+        aborts_if len(exponent) > MAX_MODEXP_INPUT_LENGTH;
+        aborts_if len(modulus) > MAX_MODEXP_INPUT_LENGTH;
+        aborts_if forall b in modulus: b == 0;
+    }
+}
+}
@@ -0,0 +1,69 @@
+address 0x1 {
+/// The module provide text encodings of bytes, such as base58, hex and bech32.
+module Codec {
+    spec module {
+        pragma verify;
+        pragma aborts_if_is_strict;
+    }
+
+    /// The max length of the input of base58 natives, as the conversion is quadratic to it.
+    const MAX_BASE58_INPUT_LENGTH: u64 = 1024;
+
+    /// Return the base58 encoding of `data`, with the bitcoin alphabet.
+    /// Aborts with 0x1E5 if `data` is longer than `MAX_BASE58_INPUT_LENGTH`.
+    native public fun base58_encode(data: vector<u8>): vector<u8>;
+
+    spec base58_encode {
+        pragma opaque = true;
+        aborts_if len(data) > MAX_BASE58_INPUT_LENGTH;
+    }
+
+    /// Return the bytes decoded from the base58 `data`, with the bitcoin alphabet.
+    /// Aborts with 0x1D5 if `data` has characters out of the alphabet, and with 0x1E5 if `data`
+    /// is longer than `MAX_BASE58_INPUT_LENGTH`.
+    native public fun base58_decode(data: vector<u8>): vector<u8>;
+
+    spec base58_decode {
+        pragma opaque = true;
+        aborts_if len(data) > MAX_BASE58_INPUT_LENGTH;
+        aborts_if [abstract] !spec_is_base58(data);
+    }
+
+    /// Whether every character of `data` is in the base58 alphabet.
+    spec fun spec_is_base58(data: vector<u8>): bool;
+
+    /// Return the lowercase hex of `data`, without `0x` prefix.
+    native public fun hex_encode(data: vector<u8>): vector<u8>;
+
+    spec hex_encode {
+        pragma opaque = true;
+        aborts_if false;
+        ensures len(result) == 2 * len(data);
+    }
+
+    /// Return the bytes decoded from the hex `data`, without `0x` prefix, in upper or lower case.
+    /// Aborts with 0x1DB if `data` has odd length or non-hex characters.
+    native public fun hex_decode(data: vector<u8>): vector<u8>;
+
+    spec hex_decode {
+        pragma opaque = true;
+        aborts_if len(data) % 2 != 0;
+        aborts_if [abstract] !spec_is_hex(data);
+        ensures len(result) * 2 == len(data);
+    }
+
+    /// Whether every character of `data` is a hex digit.
+    spec fun spec_is_hex(data: vector<u8>): bool;
+
+    /// Decode the bech32 string `data`. Returns `(true, hrp, values)` where `hrp` is in lowercase
+    /// and `values` are the 5 bits values, one value per byte.
+    /// Returns `(false, [], [])` if `data` is not a valid bech32 string, including bech32m.
+    native public fun bech32_decode(data: vector<u8>): (bool, vector<u8>, vector<u8>);
+
+    spec bech32_decode {
+        pragma opaque = true;
+        aborts_if false;
+        ensures !result_1 ==> len(result_2) == 0 && len(result_3) == 0;
+    }
+}
+}
@@ -0,0 +1,58 @@
+address 0x1 {
+/// The module provide cryptographic primitives which are not signatures or hashes.
+module Crypto {
+    spec module {
+        pragma verify;
+        pragma aborts_if_is_strict;
+    }
+
+    /// Return true if `tag` authenticates `ciphertext` and `aad` under `key` and `nonce`, with
+    /// AES-128-GCM for a 16 bytes key or AES-256-GCM for a 32 bytes key.
+    /// Returns false if `nonce` is not 12 bytes or `tag` is not 16 bytes.
+    /// Aborts with 0x1DC if `key` is not 16 or 32 bytes.
+    native public fun aes_gcm_verify(key: vector<u8>, nonce: vector<u8>, ciphertext: vector<u8>, aad: vector<u8>, tag: vector<u8>): bool;
+
+    spec aes_gcm_verify {
+        pragma opaque = true;
+        aborts_if len(key) != 16 && len(key) != 32;
+    }
+
+    /// Return the sum of the BN254 G1 points `a` and `b`. The points, and the result, are 33 bytes
+    /// compressed points.
+    /// Aborts with 0x1DF if `a` or `b` is not a compressed point on the curve.
+    native public fun bn254_g1_add(a: vector<u8>, b: vector<u8>): vector<u8>;
+
+    spec bn254_g1_add {
+        pragma opaque = true;
+        aborts_if len(a) != 33 || len(b) != 33;
+        aborts_if [abstract] !spec_is_bn254_g1_point(a) || !spec_is_bn254_g1_point(b);
+        ensures len(result) == 33;
+    }
+
+    /// Whether `p` is a compressed point on the BN254 G1 curve.
+    spec fun spec_is_bn254_g1_point(p: vector<u8>): bool;
+
+    /// The max count of public inputs of `groth16_verify`.
+    const MAX_GROTH16_PUBLIC_INPUTS: u64 = 64;
+
+    /// Verify a Groth16 `proof` over BN254 with `verifying_key` and `public_inputs`.
+    /// `verifying_key` is `alpha_g1 | beta_g2 | gamma_g2 | delta_g2 | ic`, where `ic` has one G1
+    /// point more than the public inputs, and `proof` is `a_g1 | b_g2 | c_g1`. G1 points are 64
+    /// bytes and G2 points are 128 bytes uncompressed, `public_inputs` is the concatenated 32 bytes
+    /// big-endian field elements.
+    /// Returns false if `proof` or `public_inputs` is malformed.
+    /// Aborts with 0x1E1 if `verifying_key` is malformed, and with 0x1E8 if it has more than
+    /// `MAX_GROTH16_PUBLIC_INPUTS` public inputs.
+    native public fun groth16_verify(verifying_key: vector<u8>, proof: vector<u8>, public_inputs: vector<u8>): bool;
+
+    spec groth16_verify {
+        pragma opaque = true;
+        aborts_if len(verifying_key) < 448 + 64 || (len(verifying_key) - 448) % 64 != 0;
+        aborts_if (len(verifying_key) - 448) / 64 - 1 > MAX_GROTH16_PUBLIC_INPUTS;
+        aborts_if [abstract] !spec_is_groth16_verifying_key(verifying_key);
+    }
+
+    /// Whether all the points of `verifying_key` are on the curve.
+    spec fun spec_is_groth16_verifying_key(verifying_key: vector<u8>): bool;
+}
+}
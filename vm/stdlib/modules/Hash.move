@@ -8,6 +8,93 @@ module Hash {
     native public fun sha2_256(data: vector<u8>): vector<u8>;
     native public fun sha3_256(data: vector<u8>): vector<u8>;
     native public fun keccak_256(data: vector<u8>): vector<u8>;
+
+    /// Return the 32 bytes BLAKE2b-256 hash of `data`.
+    native public fun blake2b_256(data: vector<u8>): vector<u8>;
+
+    spec blake2b_256 {
+        pragma opaque = true;
+        aborts_if false;
+        ensures len(result) == 32;
+    }
+
+    /// Return the 20 bytes RIPEMD-160 hash of `data`.
+    native public fun ripemd160(data: vector<u8>): vector<u8>;
+
+    spec ripemd160 {
+        pragma opaque = true;
+        aborts_if false;
+        ensures len(result) == 20;
+    }
+
+    /// Return the 64 bytes Keccak-512 hash of `data`.
+    native public fun keccak_512(data: vector<u8>): vector<u8>;
+
+    spec keccak_512 {
+        pragma opaque = true;
+        aborts_if false;
+        ensures len(result) == 64;
+    }
+
+    /// Return the root of the merkle tree of the 32 bytes `leaves`. The internal nodes are
+    /// `sha3_256(left | right)`, and the last node of a level with odd count is duplicated.
+    /// Aborts with 0x1D6 if `leaves` is empty, and with 0x1E6 if any leaf is not 32 bytes.
+    native public fun merkle_root_sha3(leaves: vector<vector<u8>>): vector<u8>;
+
+    spec merkle_root_sha3 {
+        pragma opaque = true;
+        aborts_if len(leaves) == 0;
+        aborts_if exists leaf in leaves: len(leaf) != 32;
+        ensures len(result) == 32;
+    }
+
+    /// Return the SipHash-2-4 of `message` with the 16 bytes `key`.
+    /// Aborts with 0x1D7 if `key` is not 16 bytes.
+    native public fun siphash(key: vector<u8>, message: vector<u8>): u64;
+
+    spec siphash {
+        pragma opaque = true;
+        aborts_if len(key) != 16;
+    }
+
+    /// Return true if `leaf` is in the merkle tree of `root` built as `merkle_root_sha3`.
+    /// `proof` is a sequence of 33 bytes items from the leaf level up, each of them is a flag
+    /// byte, 1 if the sibling is the left node and 0 otherwise, followed by the 32 bytes sibling.
+    /// Aborts with 0x1E9 if `leaf` is not 32 bytes, and with 0x1D8 if `proof` is malformed.
+    native public fun verify_merkle_proof_sha3(root: vector<u8>, leaf: vector<u8>, proof: vector<u8>): bool;
+
+    spec verify_merkle_proof_sha3 {
+        pragma opaque = true;
+        aborts_if len(leaf) != 32;
+        aborts_if len(proof) % 33 != 0;
+        aborts_if [abstract] exists i in 0..len(proof) / 33: proof[i * 33] > 1;
+    }
+
+    /// Return the circomlib compatible Poseidon hash over BN254 of `inputs`. Every input and the
+    /// result are 32 bytes big-endian elements of the BN254 scalar field.
+    /// Aborts with 0x1DE if the count of `inputs` is not in 1..=16, and with 0x1DD if an input is
+    /// not a field element.
+    native public fun poseidon_bn254(inputs: vector<vector<u8>>): vector<u8>;
+
+    spec poseidon_bn254 {
+        pragma opaque = true;
+        aborts_if len(inputs) == 0 || len(inputs) > 16;
+        aborts_if exists input in inputs: len(input) != 32;
+        aborts_if [abstract] !spec_is_bn254_field_elements(inputs);
+        ensures len(result) == 32;
+    }
+
+    /// Whether every input is less than the modulus of the BN254 scalar field.
+    spec fun spec_is_bn254_field_elements(inputs: vector<vector<u8>>): bool;
+
+    /// Return the IEEE CRC32 checksum of `data`.
+    native public fun crc32(data: vector<u8>): u64;
+
+    spec crc32 {
+        pragma opaque = true;
+        aborts_if false;
+        ensures result <= 4294967295;
+    }
 }
 
 }
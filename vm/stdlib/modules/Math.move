@@ -150,5 +150,25 @@ module Math {
         let sum = sum(nums);
         sum/(len as u128)
     }
+
+    /// Return `(true, x + y)`, or `(false, 0)` if it overflows u128, instead of aborting.
+    native public fun checked_add_u128(x: u128, y: u128): (bool, u128);
+
+    spec checked_add_u128 {
+        pragma opaque = true;
+        aborts_if false;
+        ensures x + y <= MAX_U128 ==> result_1 && result_2 == x + y;
+        ensures x + y > MAX_U128 ==> !result_1 && result_2 == 0;
+    }
+
+    /// Return `(true, x * y)`, or `(false, 0)` if it overflows u128, instead of aborting.
+    native public fun checked_mul_u128(x: u128, y: u128): (bool, u128);
+
+    spec checked_mul_u128 {
+        pragma opaque = true;
+        aborts_if false;
+        ensures x * y <= MAX_U128 ==> result_1 && result_2 == x * y;
+        ensures x * y > MAX_U128 ==> !result_1 && result_2 == 0;
+    }
 }
 }
\ No newline at end of file
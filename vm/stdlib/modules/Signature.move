@@ -5,6 +5,78 @@ module Signature {
     native public fun ed25519_validate_pubkey(public_key: vector<u8>): bool;
     native public fun ed25519_verify(signature: vector<u8>, public_key: vector<u8>, message: vector<u8>): bool;
 
+    /// Recover the 33 bytes compressed secp256k1 public key from the 32 bytes message `hash`, the
+    /// `recovery_id` in 0..=3 and the 64 bytes `r || s` `signature`.
+    /// Aborts with 0x1D0 if any input is malformed or no key can be recovered.
+    native public fun secp256k1_ecrecover(hash: vector<u8>, recovery_id: u8, signature: vector<u8>): vector<u8>;
+
+    spec secp256k1_ecrecover {
+        pragma opaque = true;
+        aborts_if [abstract] len(hash) != 32 || recovery_id > 3 || len(signature) != 64;
+        aborts_if [abstract] !spec_secp256k1_recoverable(hash, recovery_id, signature);
+        ensures [abstract] len(result) == 33;
+    }
+
+    /// Whether a public key can be recovered from the well formed input, the actual value
+    /// depends on the curve arithmetic and is left uninterpreted.
+    spec fun spec_secp256k1_recoverable(hash: vector<u8>, recovery_id: u8, signature: vector<u8>): bool;
+
+    /// Verify the aggregated bls12381 `signature` (min_pk, 96 bytes) of the messages, where the i-th
+    /// message is signed by the i-th 48 bytes public key.
+    /// Aborts with 0x1D1 if `public_keys` is empty, and with 0x1D2 if the counts of `public_keys`
+    /// and `messages` differ. Returns false for malformed keys or signature. The cost grows with
+    /// the number of keys and the total length of the messages.
+    native public fun bls12381_aggregate_verify(public_keys: vector<vector<u8>>, messages: vector<vector<u8>>, signature: vector<u8>): bool;
+
+    spec bls12381_aggregate_verify {
+        pragma opaque = true;
+        aborts_if len(public_keys) == 0;
+        aborts_if len(public_keys) != len(messages);
+    }
+
+    /// Count the valid ed25519 signatures of `message`. `public_keys` is the concatenation of
+    /// 32 bytes keys, the i-th bit of `bitmap` (from the most significant bit of the first byte)
+    /// marks that the i-th key signed, and `signatures` is the concatenation of the 64 bytes
+    /// signatures in the order of the set bits.
+    /// Aborts with 0x1D3 if `bitmap` does not match `public_keys`, and with 0x1D4 if the count of
+    /// `signatures` does not match the set bits.
+    native public fun ed25519_threshold_verify(bitmap: vector<u8>, public_keys: vector<u8>, message: vector<u8>, signatures: vector<u8>): u64;
+
+    spec ed25519_threshold_verify {
+        pragma opaque = true;
+        aborts_if [abstract] len(public_keys) % 32 != 0;
+        aborts_if [abstract] len(bitmap) != (len(public_keys) / 32 + 7) / 8;
+        aborts_if [abstract] !spec_threshold_signatures_match(bitmap, public_keys, signatures);
+        ensures [abstract] result <= len(public_keys) / 32;
+    }
+
+    /// Whether `signatures` has 64 bytes for each key marked in `bitmap`, and no bit is set
+    /// beyond the keys.
+    spec fun spec_threshold_signatures_match(bitmap: vector<u8>, public_keys: vector<u8>, signatures: vector<u8>): bool;
+
+    /// Return true if the i-th signature is a valid ed25519 signature of the i-th message by the
+    /// i-th public key for every i. The keys and signatures are checked as `ed25519_verify`, then
+    /// verified together by a randomized batch equation, which is cheaper than verifying one by
+    /// one. Keys and signature `R` points with a torsion component are rejected, so the result
+    /// does not depend on the randomness.
+    /// Aborts with 0x1DA if the counts of `public_keys`, `messages` and `signatures` differ.
+    native public fun ed25519_batch_verify(public_keys: vector<vector<u8>>, messages: vector<vector<u8>>, signatures: vector<vector<u8>>): bool;
+
+    spec ed25519_batch_verify {
+        pragma opaque = true;
+        aborts_if len(public_keys) != len(messages) || len(messages) != len(signatures);
+    }
+
+    /// Verify the BIP-340 schnorr `signature` of `message` over secp256k1, where `public_key` is
+    /// the 32 bytes x-only key and `signature` is the 64 bytes `r | s`.
+    /// Aborts with 0x1E3 if `public_key` is not 32 bytes or `signature` is not 64 bytes.
+    native public fun schnorr_verify(public_key: vector<u8>, message: vector<u8>, signature: vector<u8>): bool;
+
+    spec schnorr_verify {
+        pragma opaque = true;
+        aborts_if len(public_key) != 32 || len(signature) != 64;
+    }
+
     spec module {
         pragma intrinsic = true;
     }
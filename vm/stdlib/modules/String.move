@@ -0,0 +1,37 @@
+address 0x1 {
+/// The module provide UTF-8 functionality for the strings represented as `vector<u8>`.
+module String {
+    spec module {
+        pragma verify;
+        pragma aborts_if_is_strict;
+    }
+
+    /// Return true if `bytes` is valid UTF-8.
+    native public fun is_utf8(bytes: vector<u8>): bool;
+
+    spec is_utf8 {
+        pragma opaque = true;
+        aborts_if false;
+    }
+
+    /// Return true if `i` is a char boundary of the UTF-8 `bytes`, where `0` and `len(bytes)`
+    /// are boundaries. Returns false if `bytes` is not valid UTF-8 or `i` is beyond its length.
+    native public fun internal_char_boundary(bytes: vector<u8>, i: u64): bool;
+
+    spec internal_char_boundary {
+        pragma opaque = true;
+        aborts_if false;
+        ensures [abstract] i > len(bytes) ==> !result;
+    }
+
+    /// Parse the decimal digits `bytes` to u128. Returns `(true, value)`, or `(false, 0)` if
+    /// `bytes` is empty, has a non-digit byte or overflows u128.
+    native public fun parse_u128(bytes: vector<u8>): (bool, u128);
+
+    spec parse_u128 {
+        pragma opaque = true;
+        aborts_if false;
+        ensures [abstract] !result_1 ==> result_2 == 0;
+    }
+}
+}
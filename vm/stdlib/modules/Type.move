@@ -0,0 +1,18 @@
+address 0x1 {
+/// The module provide the names of Move types.
+module Type {
+    spec module {
+        pragma verify;
+        pragma aborts_if_is_strict;
+    }
+
+    /// Return the type tag string of `T`, such as `u64`, `vector<u8>` or
+    /// `0x00000000000000000000000000000001::STC::STC`.
+    native public fun name_of<T>(): vector<u8>;
+
+    spec name_of {
+        pragma opaque = true;
+        aborts_if false;
+    }
+}
+}
@@ -62,29 +62,14 @@ module Vector {
 
 
     /// Reverses the order of the elements in the vector `v` in place.
-    public fun reverse<Element>(v: &mut vector<Element>) {
-        let len = length(v);
-        if (len == 0) return ();
-
-        let front_index = 0;
-        let back_index = len -1;
-        while (front_index < back_index) {
-            swap(v, front_index, back_index);
-            front_index = front_index + 1;
-            back_index = back_index - 1;
-        }
-    }
+    native public fun reverse<Element>(v: &mut vector<Element>);
     spec reverse {
         pragma intrinsic = true;
     }
 
 
     /// Pushes all of the elements of the `other` vector into the `lhs` vector.
-    public fun append<Element>(lhs: &mut vector<Element>, other: vector<Element>) {
-        reverse(&mut other);
-        while (!is_empty(&other)) push_back(lhs, pop_back(&mut other));
-        destroy_empty(other);
-    }
+    native public fun append<Element>(lhs: &mut vector<Element>, other: vector<Element>);
     spec append {
         pragma intrinsic = true;
     }
@@ -99,46 +84,22 @@ module Vector {
     }
 
     /// Return true if `e` is in the vector `v`.
-    public fun contains<Element>(v: &vector<Element>, e: &Element): bool {
-        let i = 0;
-        let len = length(v);
-        while (i < len) {
-            if (borrow(v, i) == e) return true;
-            i = i + 1;
-        };
-        false
-    }
+    native public fun contains<Element>(v: &vector<Element>, e: &Element): bool;
     spec contains {
         pragma intrinsic = true;
     }
 
     /// Return `(true, i)` if `e` is in the vector `v` at index `i`.
     /// Otherwise, returns `(false, 0)`.
-    public fun index_of<Element>(v: &vector<Element>, e: &Element): (bool, u64) {
-        let i = 0;
-        let len = length(v);
-        while (i < len) {
-            if (borrow(v, i) == e) return (true, i);
-            i = i + 1;
-        };
-        (false, 0)
-    }
+    native public fun index_of<Element>(v: &vector<Element>, e: &Element): (bool, u64);
     spec index_of {
         pragma intrinsic = true;
     }
 
     /// Remove the `i`th element of the vector `v`, shifting all subsequent elements.
     /// This is O(n) and preserves ordering of elements in the vector.
-    /// Aborts if `i` is out of bounds.
-    public fun remove<Element>(v: &mut vector<Element>, i: u64): Element {
-        let len = length(v);
-        // i out of bounds; abort
-        if (i >= len) abort EINDEX_OUT_OF_BOUNDS;
-
-        len = len - 1;
-        while (i < len) swap(v, i, { i = i + 1; i });
-        pop_back(v)
-    }
+    /// Aborts with `EINDEX_OUT_OF_BOUNDS` if `i` is out of bounds.
+    native public fun remove<Element>(v: &mut vector<Element>, i: u64): Element;
     spec remove {
         pragma intrinsic = true;
     }
@@ -194,6 +155,52 @@ module Vector {
         pragma verify = false; // timeout, skip
         aborts_if sub_len == 0;
     }
+    /// Return true if `a` and `b` are equal. For vectors of the same length, the time only depends
+    /// on the length, so it can compare secrets. Vectors of different lengths return false at once.
+    native public fun constant_time_eq(a: vector<u8>, b: vector<u8>): bool;
+    spec constant_time_eq {
+        pragma opaque = true;
+        aborts_if false;
+        ensures result == (a == b);
+    }
+
+    /// Set every element of `v` to a copy of `e`, the length of `v` is not changed.
+    /// The overwritten elements are dropped, so `Element` must have `drop` as well.
+    native public fun fill<Element: copy + drop>(v: &mut vector<Element>, e: Element);
+    spec fill {
+        pragma opaque = true;
+        aborts_if false;
+        ensures len(v) == len(old(v));
+        ensures forall x in v: x == e;
+    }
+
+    /// Truncate `v` to `at` elements and return the removed tail in order.
+    /// Only vectors of `u8`, `u64`, `u128`, `bool` and `address` are supported, as a native can not
+    /// create a vector of other element types.
+    /// Aborts with `EINDEX_OUT_OF_BOUNDS` if `at` is greater than the length of `v`, and with 0x1E2
+    /// for the other element types.
+    native public fun split_off<Element>(v: &mut vector<Element>, at: u64): vector<Element>;
+    spec split_off {
+        pragma opaque = true;
+        aborts_if at > len(v) with EINDEX_OUT_OF_BOUNDS;
+        aborts_if [abstract] !spec_is_primitive<Element>() with 0x1E2;
+        ensures v == old(v)[0..at];
+        ensures result == old(v)[at..len(old(v))];
+    }
+    /// Whether `Element` is `u8`, `u64`, `u128`, `bool` or `address`.
+    spec fun spec_is_primitive<Element>(): bool;
+
+    /// Return a copy of `a` if `choice` is true, otherwise a copy of `b`. Every byte of both
+    /// vectors is combined with a mask, so neither the time nor the gas depends on `choice`.
+    /// Aborts with 0x1E4 if the lengths of `a` and `b` differ.
+    native public fun conditional_select(choice: bool, a: vector<u8>, b: vector<u8>): vector<u8>;
+    spec conditional_select {
+        pragma opaque = true;
+        aborts_if len(a) != len(b);
+        ensures choice ==> result == a;
+        ensures !choice ==> result == b;
+    }
+
      // =================================================================
     // Module Specification
 
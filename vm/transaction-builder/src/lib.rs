@@ -10,12 +10,12 @@ use starcoin_vm_types::account_address::AccountAddress;
 use starcoin_vm_types::account_config;
 use starcoin_vm_types::account_config::{core_code_address, genesis_address};
 use starcoin_vm_types::file_format::CompiledModule;
-use starcoin_vm_types::gas_schedule::GasAlgebra;
+use starcoin_vm_types::gas_schedule::{CostTable, GasAlgebra};
 use starcoin_vm_types::genesis_config::ChainId;
 use starcoin_vm_types::identifier::Identifier;
 use starcoin_vm_types::language_storage::ModuleId;
 use starcoin_vm_types::language_storage::{StructTag, TypeTag};
-use starcoin_vm_types::on_chain_config::VMConfig;
+use starcoin_vm_types::on_chain_config::{ConsensusConfig, VMConfig};
 use starcoin_vm_types::on_chain_resource::nft::NFTUUID;
 use starcoin_vm_types::token::stc::{stc_type_tag, STC_TOKEN_CODE};
 use starcoin_vm_types::token::token_code::TokenCode;
@@ -820,6 +820,86 @@ pub fn build_module_upgrade_queue(
     )
 }
 
+pub fn on_chain_config_update_type_tag(config_type: TypeTag) -> TypeTag {
+    TypeTag::Struct(StructTag {
+        address: genesis_address(),
+        module: Identifier::new("OnChainConfigDao").unwrap(),
+        name: Identifier::new("OnChainConfigUpdate").unwrap(),
+        type_params: vec![config_type],
+    })
+}
+
+pub fn build_on_chain_config_proposal_queue(
+    proposal_address: AccountAddress,
+    proposal_id: u64,
+    token_code: TokenCode,
+    config_type: TypeTag,
+) -> ScriptFunction {
+    ScriptFunction::new(
+        ModuleId::new(core_code_address(), Identifier::new("Dao").unwrap()),
+        Identifier::new("queue_proposal_action").unwrap(),
+        vec![
+            token_code
+                .try_into()
+                .expect("Token code to type tag should success"),
+            on_chain_config_update_type_tag(config_type),
+        ],
+        vec![
+            bcs_ext::to_bytes(&proposal_address).unwrap(),
+            bcs_ext::to_bytes(&proposal_id).unwrap(),
+        ],
+    )
+}
+
+pub fn build_on_chain_config_proposal_execute(
+    proposal_address: AccountAddress,
+    proposal_id: u64,
+    token_code: TokenCode,
+    config_type: TypeTag,
+) -> ScriptFunction {
+    ScriptFunction::new(
+        ModuleId::new(
+            core_code_address(),
+            Identifier::new("OnChainConfigScripts").unwrap(),
+        ),
+        Identifier::new("execute_on_chain_config_proposal_v2").unwrap(),
+        vec![
+            token_code
+                .try_into()
+                .expect("Token code to type tag should success"),
+            config_type,
+        ],
+        vec![
+            bcs_ext::to_bytes(&proposal_address).unwrap(),
+            bcs_ext::to_bytes(&proposal_id).unwrap(),
+        ],
+    )
+}
+
+/// Destroy a defeated or executed on chain config proposal, which releases the proposal resource
+/// of the proposer.
+pub fn build_on_chain_config_proposal_destroy(
+    proposal_address: AccountAddress,
+    proposal_id: u64,
+    token_code: TokenCode,
+    config_type: TypeTag,
+) -> ScriptFunction {
+    ScriptFunction::new(
+        ModuleId::new(core_code_address(), Identifier::new("Dao").unwrap()),
+        Identifier::new("destroy_terminated_proposal").unwrap(),
+        vec![
+            token_code
+                .try_into()
+                .expect("Token code to type tag should success"),
+            on_chain_config_update_type_tag(config_type),
+        ],
+        vec![
+            bcs_ext::to_bytes(&proposal_address).unwrap(),
+            bcs_ext::to_bytes(&proposal_id).unwrap(),
+        ],
+    )
+}
+
 pub fn build_vm_config_upgrade_proposal(vm_config: VMConfig, exec_delay: u64) -> ScriptFunction {
     let gas_constants = &vm_config.gas_schedule.gas_constants;
     ScriptFunction::new(
@@ -852,6 +932,42 @@ pub fn build_vm_config_upgrade_proposal(vm_config: VMConfig, exec_delay: u64) ->
     )
 }
 
+pub fn build_consensus_config_upgrade_proposal(
+    consensus_config: ConsensusConfig,
+    exec_delay: u64,
+) -> ScriptFunction {
+    ScriptFunction::new(
+        ModuleId::new(
+            core_code_address(),
+            Identifier::new("OnChainConfigScripts").unwrap(),
+        ),
+        Identifier::new("propose_update_consensus_config").unwrap(),
+        vec![],
+        vec![
+            bcs_ext::to_bytes(&consensus_config.uncle_rate_target).unwrap(),
+            bcs_ext::to_bytes(&consensus_config.base_block_time_target).unwrap(),
+            bcs_ext::to_bytes(&consensus_config.base_reward_per_block).unwrap(),
+            bcs_ext::to_bytes(&consensus_config.base_reward_per_uncle_percent).unwrap(),
+            bcs_ext::to_bytes(&consensus_config.epoch_block_count).unwrap(),
+            bcs_ext::to_bytes(&consensus_config.base_block_difficulty_window).unwrap(),
+            bcs_ext::to_bytes(&consensus_config.min_block_time_target).unwrap(),
+            bcs_ext::to_bytes(&consensus_config.max_block_time_target).unwrap(),
+            bcs_ext::to_bytes(&consensus_config.base_max_uncles_per_block).unwrap(),
+            bcs_ext::to_bytes(&consensus_config.base_block_gas_limit).unwrap(),
+            bcs_ext::to_bytes(&consensus_config.strategy).unwrap(),
+            bcs_ext::to_bytes(&exec_delay).unwrap(),
+        ],
+    )
+}
+
+pub fn build_gas_schedule_upgrade_proposal(
+    gas_schedule: CostTable,
+    exec_delay: u64,
+) -> ScriptFunction {
+    // the on chain gas schedule is a part of VMConfig.
+    build_vm_config_upgrade_proposal(VMConfig { gas_schedule }, exec_delay)
+}
+
 pub fn build_empty_script() -> ScriptFunction {
     ScriptFunction::new(
         ModuleId::new(
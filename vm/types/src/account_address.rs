@@ -14,6 +14,20 @@ pub fn from_public_key(public_key: &Ed25519PublicKey) -> AccountAddress {
     AuthenticationKey::ed25519(public_key).derived_address()
 }
 
+/// Appended to the preimage of a resource account address, so it never collides with the
+/// address derived from an authentication key preimage.
+pub const RESOURCE_ACCOUNT_SCHEME: u8 = 0xFF;
+
+/// Derive the address of a resource account created by `source` with `seed`, the same as
+/// the `Account::create_resource_address` native.
+pub fn create_resource_address(source: &AccountAddress, seed: &[u8]) -> AccountAddress {
+    AuthenticationKey::new(
+        *HashValue::sha3_256_of(&[source.as_ref(), seed, &[RESOURCE_ACCOUNT_SCHEME]].concat())
+            .as_ref(),
+    )
+    .derived_address()
+}
+
 // Define the Hasher used for hashing AccountAddress types. In order to properly use the
 // CryptoHasher derive macro we need to have this in its own module so that it doesn't conflict
 // with the imported `AccountAddress` from move-core-types. It needs to have the same name since
@@ -55,4 +69,15 @@ mod test {
 
         assert_eq!(address.hash(), HashValue::new(hash));
     }
+
+    #[test]
+    fn resource_address() {
+        let source: AccountAddress = "ca843279e3427144cead5e4d5999a3d0".parse().unwrap();
+        let expect: AccountAddress = "8b8524f4ee4f9d86d277c61775a56535".parse().unwrap();
+        assert_eq!(create_resource_address(&source, &[]), expect);
+
+        let source: AccountAddress = "00000000000000000000000000000001".parse().unwrap();
+        let expect: AccountAddress = "ebe77ee673c91ac511d18f5475508e1d".parse().unwrap();
+        assert_eq!(create_resource_address(&source, b"seed"), expect);
+    }
 }
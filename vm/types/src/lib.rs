@@ -3,10 +3,12 @@
 //TODO FIXME for fuzzing Arbitrary;
 #![allow(clippy::unit_arg)]
 mod language_storage_ext;
+mod native_cost_index;
 
 pub mod account_address;
 
 pub mod gas_schedule {
+    pub use crate::native_cost_index::StarcoinNativeCostIndex;
     pub use move_core_types::gas_schedule::*;
     pub use move_vm_types::gas_schedule::*;
 }
@@ -0,0 +1,54 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use vm::file_format::NUMBER_OF_NATIVE_FUNCTIONS;
+
+/// The native table index of the natives implemented by starcoin. They continue the
+/// `NativeCostIndex` of move, so the on chain gas schedule prices them the same way.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum StarcoinNativeCostIndex {
+    KECCAK_512 = NUMBER_OF_NATIVE_FUNCTIONS as u8,
+    BLAKE2B_256,
+    RIPEMD160,
+    MERKLE_ROOT_SHA3,
+    VERIFY_MERKLE_PROOF_SHA3,
+    SIPHASH,
+    CRC32,
+    POSEIDON_BN254,
+    BIGINT_MODEXP,
+    MATH_CHECKED_U128,
+    AES_GCM_VERIFY,
+    BN254_G1_ADD,
+    GROTH16_VERIFY,
+    BCS_FROM_BYTES,
+    CREATE_RESOURCE_ADDRESS,
+    ED25519_BATCH_VERIFY,
+    SECP256K1_ECRECOVER,
+    BLS12381_AGGREGATE_VERIFY,
+    SCHNORR_VERIFY,
+    BASE58,
+    HEX,
+    BECH32,
+    GROTH16_PUBLIC_INPUT,
+    BLS12381_AGGREGATE_VERIFY_PER_BYTE,
+    STRING_IS_UTF8,
+    STRING_INTERNAL_CHAR_BOUNDARY,
+    VECTOR_APPEND,
+    VECTOR_REVERSE,
+    VECTOR_INDEX_OF,
+    VECTOR_CONTAINS,
+    VECTOR_REMOVE,
+    VECTOR_CONSTANT_TIME_EQ,
+    STRING_PARSE_U128,
+    VECTOR_FILL,
+    VECTOR_SPLIT_OFF,
+    VECTOR_CONDITIONAL_SELECT,
+}
+
+impl StarcoinNativeCostIndex {
+    /// The length of a native table which has all the move and starcoin natives.
+    pub const NUMBER_OF_NATIVE_FUNCTIONS: usize =
+        StarcoinNativeCostIndex::VECTOR_CONDITIONAL_SELECT as usize + 1;
+}
@@ -3,7 +3,7 @@
 
 //! This file contains the starting gas schedule published at genesis.
 
-use crate::gas_schedule::{CostTable, GasConstants, GasCost};
+use crate::gas_schedule::{CostTable, GasConstants, GasCost, StarcoinNativeCostIndex as S};
 use move_vm_types::gas_schedule::NativeCostIndex as N;
 use vm::{
     file_format::{
@@ -175,6 +175,80 @@ pub fn initial_native_table() -> Vec<GasCost> {
     native_table
 }
 
+/// The native table with the starcoin natives appended, for the networks whose genesis is not
+/// fixed yet, and as the cost of the starcoin natives missing in an on chain native table.
+pub fn latest_native_table() -> Vec<GasCost> {
+    let mut raw_native_table = vec![
+        (S::KECCAK_512, GasCost::new(64, 1)),
+        (S::BLAKE2B_256, GasCost::new(64, 1)),
+        (S::RIPEMD160, GasCost::new(21, 1)),
+        // about the cost of sha3_256 on the 64 bytes of two child nodes.
+        (S::MERKLE_ROOT_SHA3, GasCost::new(4096, 1)),
+        (S::VERIFY_MERKLE_PROOF_SHA3, GasCost::new(4096, 1)),
+        // siphash is cheaper than sha2_256 per byte.
+        (S::SIPHASH, GasCost::new(8, 1)),
+        (S::CRC32, GasCost::new(4, 1)),
+        // each input adds a state element to the permutation rounds.
+        (S::POSEIDON_BN254, GasCost::new(2000, 1)),
+        // per unit of the EIP-2565 complexity.
+        (S::BIGINT_MODEXP, GasCost::new(2, 1)),
+        // about the cost of an arithmetic instruction.
+        (S::MATH_CHECKED_U128, GasCost::new(1, 1)),
+        (S::AES_GCM_VERIFY, GasCost::new(16, 1)),
+        (S::BN254_G1_ADD, GasCost::new(500, 1)),
+        (S::GROTH16_VERIFY, GasCost::new(100000, 1)),
+        (S::BCS_FROM_BYTES, GasCost::new(26, 1)),
+        (S::CREATE_RESOURCE_ADDRESS, GasCost::new(30, 1)),
+        // per message byte, about 2/3 of ed25519_verify.
+        (S::ED25519_BATCH_VERIFY, GasCost::new(40, 1)),
+        (S::SECP256K1_ECRECOVER, GasCost::new(3351, 1)),
+        // per public key, the pairings dominate the cost.
+        (S::BLS12381_AGGREGATE_VERIFY, GasCost::new(3351, 1)),
+        (S::SCHNORR_VERIFY, GasCost::new(3351, 1)),
+        // charged on the square of the input length.
+        (S::BASE58, GasCost::new(1, 1)),
+        (S::HEX, GasCost::new(2, 1)),
+        // the checksum is a polynomial over each character.
+        (S::BECH32, GasCost::new(8, 1)),
+        // a G1 scalar multiplication per public input of groth16.
+        (S::GROTH16_PUBLIC_INPUT, GasCost::new(4000, 1)),
+        // per message byte hashed to the curve, as ed25519_verify charges its message.
+        (S::BLS12381_AGGREGATE_VERIFY_PER_BYTE, GasCost::new(61, 1)),
+        // per byte validated as UTF-8.
+        (S::STRING_IS_UTF8, GasCost::new(21, 1)),
+        (S::STRING_INTERNAL_CHAR_BOUNDARY, GasCost::new(21, 1)),
+        // per element moved, as push_back.
+        (S::VECTOR_APPEND, GasCost::new(53, 1)),
+        // per pair of elements swapped, as swap.
+        (S::VECTOR_REVERSE, GasCost::new(1436, 1)),
+        // per element compared.
+        (S::VECTOR_INDEX_OF, GasCost::new(98, 1)),
+        // per element compared.
+        (S::VECTOR_CONTAINS, GasCost::new(98, 1)),
+        // per element shifted, as swap.
+        (S::VECTOR_REMOVE, GasCost::new(1436, 1)),
+        // per byte of the longer vector.
+        (S::VECTOR_CONSTANT_TIME_EQ, GasCost::new(21, 1)),
+        // per decimal digit.
+        (S::STRING_PARSE_U128, GasCost::new(21, 1)),
+        // per element written, as push_back.
+        (S::VECTOR_FILL, GasCost::new(53, 1)),
+        // per element moved, as push_back.
+        (S::VECTOR_SPLIT_OFF, GasCost::new(53, 1)),
+        // per byte selected.
+        (S::VECTOR_CONDITIONAL_SELECT, GasCost::new(21, 1)),
+    ];
+    raw_native_table.sort_by_key(|cost| cost.0 as u64);
+    let mut native_table = initial_native_table();
+    native_table.extend(raw_native_table.into_iter().map(|(_, cost)| cost));
+
+    debug_assert!(
+        native_table.len() == S::NUMBER_OF_NATIVE_FUNCTIONS,
+        "all starcoin native functions must be in the cost table"
+    );
+    native_table
+}
+
 pub fn v1_native_table() -> Vec<GasCost> {
     let mut raw_native_table = vec![
         (N::SHA2_256, GasCost::new(21, 1)),
@@ -26,11 +26,15 @@ pub use self::{
     consensus_config::{consensus_config_type_tag, ConsensusConfig, CONSENSUS_CONFIG_IDENTIFIER},
     dao_config::DaoConfig,
     genesis_gas_schedule::{
-        init_cost_table, initial_instruction_table, initial_native_table, v1_native_table,
+        init_cost_table, initial_instruction_table, initial_native_table, latest_native_table,
+        v1_native_table,
     },
     move_lang_version::MoveLanguageVersion,
     version::{version_config_type_tag, Version, VERSION_CONFIG_IDENTIFIER},
-    vm_config::{vm_config_type_tag, TransactionPublishOption, VMConfig, SCRIPT_HASH_LENGTH},
+    vm_config::{
+        vm_config_type_tag, CostTableInner, TransactionPublishOption, VMConfig, VMConfigInner,
+        SCRIPT_HASH_LENGTH,
+    },
 };
 pub use crate::on_chain_resource::GlobalTimeOnChain;
 
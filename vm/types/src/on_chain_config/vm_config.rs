@@ -83,19 +83,28 @@ pub struct VMConfig {
     pub gas_schedule: CostTable,
 }
 
+/// The Move layout of `CostTable`, the tables are BCS encoded.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
-struct CostTableInner {
+pub struct CostTableInner {
     pub instruction_table: Vec<u8>,
     pub native_table: Vec<u8>,
     pub gas_constants: GasConstants,
 }
 
+/// The Move layout of `VMConfig`.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[allow(clippy::upper_case_acronyms)]
-struct VMConfigInner {
+pub struct VMConfigInner {
     pub gas_schedule: CostTableInner,
 }
 
+impl VMConfigInner {
+    pub fn as_vm_config(&self) -> Result<VMConfig> {
+        let gas_schedule = self.gas_schedule.as_cost_table()?;
+        Ok(VMConfig { gas_schedule })
+    }
+}
+
 impl CostTableInner {
     pub fn as_cost_table(&self) -> Result<CostTable> {
         let instruction_table = bcs_ext::from_bytes(&self.instruction_table)?;
@@ -119,8 +128,7 @@ impl OnChainConfig for VMConfig {
                 e
             )
         })?;
-        let gas_schedule = raw_vm_config.gas_schedule.as_cost_table()?;
-        Ok(VMConfig { gas_schedule })
+        raw_vm_config.as_vm_config()
     }
 }
 
@@ -1,14 +1,64 @@
+use anyhow::{ensure, Result};
 use move_stdlib::natives::{bcs, event, hash, signer, vector};
 use move_vm_runtime::native_functions::{NativeFunction, NativeFunctionTable};
 use starcoin_natives::{account, debug, signature};
+use starcoin_vm_types::account_address::AccountAddress;
 use starcoin_vm_types::identifier::Identifier;
 use starcoin_vm_types::language_storage::CORE_CODE_ADDRESS;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether the default `NativeFunctionOptions` enable the debug natives, see
+/// `set_debug_natives_enabled`.
+static DEBUG_NATIVES_ENABLED: AtomicBool = AtomicBool::new(cfg!(feature = "testing"));
+
+/// Set whether the VMs created with the default `NativeFunctionOptions`, such as
+/// `StarcoinVM::new`, register the real debug natives. It applies to the whole process, the node
+/// sets it at startup from `VmRuntimeConfig`.
+pub fn set_debug_natives_enabled(enable: bool) {
+    DEBUG_NATIVES_ENABLED.store(enable, Ordering::Relaxed);
+}
+
+/// Whether the VMs created with the default `NativeFunctionOptions` register the real debug
+/// natives, false by default except in `testing` feature builds.
+pub fn debug_natives_enabled() -> bool {
+    DEBUG_NATIVES_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Whether the binary is built with the `testing` feature, in which the debug natives are enabled
+/// by default and the testing only natives can be registered.
+pub fn is_testing_build() -> bool {
+    cfg!(feature = "testing")
+}
+
+/// Options to control which implementation of some natives is registered.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NativeFunctionOptions {
+    /// If false, `Debug::print` and `Debug::print_stack_trace` are registered as no-op.
+    pub enable_debug: bool,
+    /// If true, register `UnitTest::create_signers_for_testing` in `testing` feature builds.
+    pub allow_unsafe_test_natives: bool,
+}
+
+impl Default for NativeFunctionOptions {
+    fn default() -> Self {
+        Self {
+            enable_debug: debug_natives_enabled(),
+            allow_unsafe_test_natives: false,
+        }
+    }
+}
+
+/// The function returns all native functions supported by Starcoin, with default options.
+pub fn starcoin_natives() -> NativeFunctionTable {
+    starcoin_natives_with_options(NativeFunctionOptions::default())
+}
 
 /// The function returns all native functions supported by Starcoin.
 /// NOTICE:
 /// - mostly re-use natives defined in move-stdlib.
 /// - be careful with the native cost table index used in the implementation
-pub fn starcoin_natives() -> NativeFunctionTable {
+pub fn starcoin_natives_with_options(options: NativeFunctionOptions) -> NativeFunctionTable {
     const NATIVES: &[(&str, &str, NativeFunction)] = &[
         ("Hash", "sha2_256", hash::native_sha2_256),
         ("Hash", "sha3_256", hash::native_sha3_256),
@@ -17,12 +67,75 @@ pub fn starcoin_natives() -> NativeFunctionTable {
             "keccak_256",
             starcoin_natives::hash::native_keccak_256,
         ),
+        (
+            "Hash",
+            "keccak_512",
+            starcoin_natives::hash::native_keccak_512,
+        ),
+        (
+            "Hash",
+            "blake2b_256",
+            starcoin_natives::hash::native_blake2b_256,
+        ),
+        (
+            "Hash",
+            "ripemd160",
+            starcoin_natives::hash::native_ripemd160,
+        ),
+        (
+            "Hash",
+            "merkle_root_sha3",
+            starcoin_natives::hash::native_merkle_root_sha3,
+        ),
+        (
+            "Hash",
+            "verify_merkle_proof_sha3",
+            starcoin_natives::hash::native_verify_merkle_proof_sha3,
+        ),
+        ("Hash", "siphash", starcoin_natives::hash::native_siphash),
+        ("Hash", "crc32", starcoin_natives::hash::native_crc32),
+        (
+            "Hash",
+            "poseidon_bn254",
+            starcoin_natives::hash::native_poseidon_bn254,
+        ),
+        ("BigInt", "modexp", starcoin_natives::bigint::native_modexp),
+        (
+            "Math",
+            "checked_add_u128",
+            starcoin_natives::math::native_checked_add_u128,
+        ),
+        (
+            "Math",
+            "checked_mul_u128",
+            starcoin_natives::math::native_checked_mul_u128,
+        ),
+        (
+            "Crypto",
+            "aes_gcm_verify",
+            starcoin_natives::crypto::native_aes_gcm_verify,
+        ),
+        (
+            "Crypto",
+            "bn254_g1_add",
+            starcoin_natives::crypto::native_bn254_g1_add,
+        ),
+        (
+            "Crypto",
+            "groth16_verify",
+            starcoin_natives::crypto::native_groth16_verify,
+        ),
         ("BCS", "to_bytes", bcs::native_to_bytes),
         (
             "BCS",
             "to_address",
             starcoin_natives::bcs::native_to_address,
         ),
+        (
+            "BCS",
+            "from_bytes",
+            starcoin_natives::bcs::native_from_bytes,
+        ),
         (
             "Signature",
             "ed25519_validate_pubkey",
@@ -33,6 +146,31 @@ pub fn starcoin_natives() -> NativeFunctionTable {
             "ed25519_verify",
             signature::native_ed25519_signature_verification,
         ),
+        (
+            "Signature",
+            "ed25519_threshold_verify",
+            signature::native_ed25519_threshold_verify,
+        ),
+        (
+            "Signature",
+            "ed25519_batch_verify",
+            signature::native_ed25519_batch_verify,
+        ),
+        (
+            "Signature",
+            "secp256k1_ecrecover",
+            signature::native_secp256k1_ecrecover,
+        ),
+        (
+            "Signature",
+            "bls12381_aggregate_verify",
+            signature::native_bls12381_aggregate_verify,
+        ),
+        (
+            "Signature",
+            "schnorr_verify",
+            signature::native_schnorr_verify,
+        ),
         ("Vector", "length", vector::native_length),
         ("Vector", "empty", vector::native_empty),
         ("Vector", "borrow", vector::native_borrow),
@@ -41,6 +179,39 @@ pub fn starcoin_natives() -> NativeFunctionTable {
         ("Vector", "pop_back", vector::native_pop),
         ("Vector", "destroy_empty", vector::native_destroy_empty),
         ("Vector", "swap", vector::native_swap),
+        ("Vector", "append", starcoin_natives::vector::native_append),
+        (
+            "Vector",
+            "reverse",
+            starcoin_natives::vector::native_reverse,
+        ),
+        (
+            "Vector",
+            "index_of",
+            starcoin_natives::vector::native_index_of,
+        ),
+        (
+            "Vector",
+            "contains",
+            starcoin_natives::vector::native_contains,
+        ),
+        ("Vector", "remove", starcoin_natives::vector::native_remove),
+        ("Vector", "fill", starcoin_natives::vector::native_fill),
+        (
+            "Vector",
+            "split_off",
+            starcoin_natives::vector::native_split_off,
+        ),
+        (
+            "Vector",
+            "constant_time_eq",
+            starcoin_natives::vector::native_constant_time_eq,
+        ),
+        (
+            "Vector",
+            "conditional_select",
+            starcoin_natives::vector::native_conditional_select,
+        ),
         (
             "Event",
             "write_to_event_store",
@@ -48,28 +219,102 @@ pub fn starcoin_natives() -> NativeFunctionTable {
         ),
         ("Account", "create_signer", account::native_create_signer),
         ("Account", "destroy_signer", account::native_destroy_signer),
+        (
+            "Account",
+            "create_resource_address",
+            account::native_create_resource_address,
+        ),
         ("Signer", "borrow_address", signer::native_borrow_address),
+        (
+            "String",
+            "is_utf8",
+            starcoin_natives::string::native_is_utf8,
+        ),
+        (
+            "String",
+            "internal_char_boundary",
+            starcoin_natives::string::native_internal_char_boundary,
+        ),
         (
             "Token",
             "name_of",
             starcoin_natives::token::native_token_name_of,
         ),
-        ("Debug", "print", debug::native_print),
         (
-            "Debug",
-            "print_stack_trace",
-            debug::native_print_stack_trace,
+            "String",
+            "parse_u128",
+            starcoin_natives::string::native_parse_u128,
+        ),
+        (
+            "Codec",
+            "base58_encode",
+            starcoin_natives::codec::native_base58_encode,
+        ),
+        (
+            "Codec",
+            "base58_decode",
+            starcoin_natives::codec::native_base58_decode,
+        ),
+        (
+            "Codec",
+            "hex_encode",
+            starcoin_natives::codec::native_hex_encode,
         ),
-        #[cfg(feature = "testing")]
         (
+            "Codec",
+            "hex_decode",
+            starcoin_natives::codec::native_hex_decode,
+        ),
+        (
+            "Codec",
+            "bech32_decode",
+            starcoin_natives::codec::native_bech32_decode,
+        ),
+        (
+            "Type",
+            "name_of",
+            starcoin_natives::type_name::native_type_name_of,
+        ),
+    ];
+    // `UnitTest::create_signers_for_testing` can create arbitrary signers,
+    // so require an explicit opt-in besides the `testing` feature.
+    #[cfg(feature = "testing")]
+    let unsafe_test_natives: &[(&str, &str, NativeFunction)] = if options.allow_unsafe_test_natives
+    {
+        &[(
             "UnitTest",
             "create_signers_for_testing",
             move_stdlib::natives::unit_test::native_create_signers_for_testing,
-        ),
-    ];
+        )]
+    } else {
+        &[]
+    };
+    #[cfg(not(feature = "testing"))]
+    let unsafe_test_natives: &[(&str, &str, NativeFunction)] = &[];
+    let debug_natives: [(&str, &str, NativeFunction); 2] = if options.enable_debug {
+        [
+            ("Debug", "print", debug::native_print),
+            (
+                "Debug",
+                "print_stack_trace",
+                debug::native_print_stack_trace,
+            ),
+        ]
+    } else {
+        [
+            ("Debug", "print", debug::native_print_noop),
+            (
+                "Debug",
+                "print_stack_trace",
+                debug::native_print_stack_trace_noop,
+            ),
+        ]
+    };
     NATIVES
         .iter()
         .cloned()
+        .chain(debug_natives.iter().cloned())
+        .chain(unsafe_test_natives.iter().cloned())
         .map(|(module_name, func_name, func)| {
             (
                 CORE_CODE_ADDRESS,
@@ -80,3 +325,84 @@ pub fn starcoin_natives() -> NativeFunctionTable {
         })
         .collect()
 }
+
+/// Describe a registered native function.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct NativeDescriptor {
+    pub address: AccountAddress,
+    pub module_name: Identifier,
+    pub func_name: Identifier,
+    /// If true, the native is only registered in `testing` feature builds.
+    pub testing_only: bool,
+}
+
+/// The (module, function) of natives which are only registered in `testing` feature builds.
+const TESTING_ONLY_NATIVES: &[(&str, &str)] = &[("UnitTest", "create_signers_for_testing")];
+
+/// Return the descriptors of all native functions returned by `starcoin_natives`.
+pub fn native_descriptors() -> Vec<NativeDescriptor> {
+    native_descriptors_with_options(NativeFunctionOptions::default())
+}
+
+/// Return the descriptors of all native functions returned by `starcoin_natives_with_options`.
+pub fn native_descriptors_with_options(options: NativeFunctionOptions) -> Vec<NativeDescriptor> {
+    starcoin_natives_with_options(options)
+        .into_iter()
+        .map(|(address, module_name, func_name, _)| {
+            let testing_only = TESTING_ONLY_NATIVES.iter().any(|(module, func)| {
+                module_name.as_str() == *module && func_name.as_str() == *func
+            });
+            NativeDescriptor {
+                address,
+                module_name,
+                func_name,
+                testing_only,
+            }
+        })
+        .collect()
+}
+
+/// Build a native function table with custom natives layered on the starcoin natives.
+pub struct NativeTableBuilder {
+    natives: NativeFunctionTable,
+}
+
+impl Default for NativeTableBuilder {
+    fn default() -> Self {
+        Self::new(NativeFunctionOptions::default())
+    }
+}
+
+impl NativeTableBuilder {
+    pub fn new(options: NativeFunctionOptions) -> Self {
+        Self {
+            natives: starcoin_natives_with_options(options),
+        }
+    }
+
+    pub fn with_native(
+        mut self,
+        address: AccountAddress,
+        module_name: Identifier,
+        func_name: Identifier,
+        func: NativeFunction,
+    ) -> Self {
+        self.natives.push((address, module_name, func_name, func));
+        self
+    }
+
+    /// Build the table, fail if a (address, module, function) is registered more than once.
+    pub fn build(self) -> Result<NativeFunctionTable> {
+        let mut registered = HashSet::new();
+        for (address, module_name, func_name, _) in &self.natives {
+            ensure!(
+                registered.insert((address, module_name, func_name)),
+                "Duplicated native function {}::{}::{}",
+                address,
+                module_name,
+                func_name
+            );
+        }
+        Ok(self.natives)
+    }
+}
@@ -7,6 +7,7 @@ use crate::errors::{
     convert_normal_success_epilogue_error, convert_prologue_runtime_error, error_split,
 };
 use crate::metrics::{BLOCK_UNCLES, TXN_EXECUTION_GAS_USAGE};
+use crate::natives::{starcoin_natives_with_options, NativeFunctionOptions};
 use anyhow::{format_err, Error, Result};
 use crypto::HashValue;
 use move_vm_runtime::data_cache::MoveStorage;
@@ -80,7 +81,11 @@ impl Default for StarcoinVM {
 
 impl StarcoinVM {
     pub fn new() -> Self {
-        let inner = MoveVM::new(super::natives::starcoin_natives())
+        Self::new_with_native_options(NativeFunctionOptions::default())
+    }
+
+    pub fn new_with_native_options(native_options: NativeFunctionOptions) -> Self {
+        let inner = MoveVM::new(starcoin_natives_with_options(native_options))
             .expect("should be able to create Move VM; check if there are duplicated natives");
         Self {
             move_vm: Arc::new(inner),
@@ -1,5 +1,8 @@
 use anyhow::Result;
+use starcoin_vm_runtime::natives::{NativeFunctionOptions, NativeTableBuilder};
 use starcoin_vm_types::access::ModuleAccess;
+use starcoin_vm_types::identifier::Identifier;
+use starcoin_vm_types::language_storage::CORE_CODE_ADDRESS;
 use starcoin_vm_types::normalized::Function;
 use std::collections::HashSet;
 use stdlib::load_latest_compiled_modules;
@@ -30,9 +33,15 @@ pub fn test_native_function_matches() -> Result<()> {
         })
         .collect();
 
-    let mut native_function_table = starcoin_vm_runtime::natives::starcoin_natives()
-        .iter()
-        .map(|(addr, m_name, f_name, _)| (*addr, m_name.to_string(), f_name.to_string()))
+    let mut native_function_table = starcoin_vm_runtime::natives::native_descriptors()
+        .into_iter()
+        .map(|d| {
+            (
+                d.address,
+                d.module_name.to_string(),
+                d.func_name.to_string(),
+            )
+        })
         .collect::<HashSet<_>>();
 
     for f in native_functions {
@@ -48,3 +57,45 @@ pub fn test_native_function_matches() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+pub fn test_native_table_builder() -> Result<()> {
+    let default_len = starcoin_vm_runtime::natives::starcoin_natives().len();
+    let table = NativeTableBuilder::default()
+        .with_native(
+            CORE_CODE_ADDRESS,
+            Identifier::new("Custom")?,
+            Identifier::new("sha3_256")?,
+            move_stdlib::natives::hash::native_sha3_256,
+        )
+        .build()?;
+    assert_eq!(table.len(), default_len + 1);
+
+    let result = NativeTableBuilder::default()
+        .with_native(
+            CORE_CODE_ADDRESS,
+            Identifier::new("Hash")?,
+            Identifier::new("sha3_256")?,
+            move_stdlib::natives::hash::native_sha3_256,
+        )
+        .build();
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+pub fn test_native_descriptors_testing_only() {
+    assert!(starcoin_vm_runtime::natives::native_descriptors()
+        .iter()
+        .all(|d| !d.testing_only));
+
+    let options = NativeFunctionOptions {
+        allow_unsafe_test_natives: true,
+        ..NativeFunctionOptions::default()
+    };
+    let testing_only = starcoin_vm_runtime::natives::native_descriptors_with_options(options)
+        .into_iter()
+        .filter(|d| d.testing_only)
+        .count();
+    assert_eq!(testing_only, if cfg!(feature = "testing") { 1 } else { 0 });
+}